@@ -1,17 +1,95 @@
 use crate::error::ContractError;
+use crate::execute::airdrop::{exec_claim_airdrop, exec_clawback_unclaimed, exec_register_airdrop};
+use crate::execute::auction::{
+    exec_configure_auction, exec_place_bid, exec_reclaim_bid, exec_settle_auction,
+};
+use crate::execute::automation::{
+    exec_register_clock, exec_run_automation, exec_set_automation, handle_automation_reply,
+    sudo_end_block,
+};
+use crate::execute::basket::{
+    exec_cancel_basket_rebalance, exec_configure_basket, exec_execute_basket_rebalance,
+    exec_mint_basket, exec_propose_basket_rebalance, exec_redeem_basket,
+};
+use crate::execute::bridge::{exec_bridge_transfer, exec_set_bridge_peer};
 use crate::execute::burn::exec_burn;
-use crate::execute::mint::{exec_mint, transfer_minted_coins};
+use crate::execute::cdp::{
+    exec_borrow, exec_configure_cdp, exec_deposit_collateral, exec_liquidate, exec_repay,
+    exec_withdraw_collateral,
+};
+use crate::execute::curve::{exec_buy_from_curve, exec_configure_curve, exec_sell_to_curve};
+use crate::execute::distribution::{exec_append_initial_balances, exec_continue_distribution};
+use crate::execute::emission::{exec_add_emission, exec_emit, exec_remove_emission};
+use crate::execute::holders::{
+    exec_enable_holder_tracking, exec_reconcile_holder, sudo_track_before_send,
+};
+use crate::execute::hooks::{exec_add_hook, exec_remove_hook, handle_hook_reply};
+use crate::execute::migration::{
+    exec_claim_migration, exec_finalize_cw20_migration, exec_push_migration,
+    exec_snapshot_cw20_balances, exec_start_cw20_migration, exec_upload_migration_snapshot,
+};
+use crate::execute::mint::{exec_mint, exec_mint_and_call, transfer_minted_coins};
+use crate::execute::payment_stream::{
+    exec_cancel_stream, exec_create_stream, exec_withdraw_stream,
+};
+use crate::execute::raise::{
+    exec_claim, exec_configure_raise, exec_contribute, exec_refund, exec_withdraw_raise_proceeds,
+};
+use crate::execute::receive::exec_receive;
+use crate::execute::remote_mint::{
+    exec_mint_to_remote, handle_remote_transfer_reply, sudo_ibc_lifecycle,
+};
 use crate::execute::remove_denom_admin::exec_remove_denom_admin;
+use crate::execute::sale::{exec_buy, exec_configure_sale};
+use crate::execute::scheduled_mint::{
+    exec_cancel_scheduled_mint, exec_execute_due_mints, exec_schedule_mint,
+};
+use crate::execute::set_cw20_wrapper::exec_set_cw20_wrapper;
 use crate::execute::set_denom_admin::exec_set_denom_admin;
 use crate::execute::set_denom_metadata::exec_set_denom_metadata;
 use crate::execute::set_manager::exec_set_manager;
+use crate::execute::set_max_supply::exec_set_max_supply;
+use crate::execute::set_mint_fee::exec_set_mint_fee;
+use crate::execute::staking::{
+    exec_claim_unbonded, exec_compound, exec_configure_staking, exec_stake, exec_unstake,
+};
+use crate::execute::unwrap::exec_unwrap;
+use crate::execute::voucher::{exec_redeem_voucher, exec_set_voucher_signer};
+use crate::execute::wrapped_asset::{exec_configure_wrapped_asset, exec_deposit, exec_withdraw};
 use crate::execute::Context;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
+use crate::query::airdrop::{query_airdrop_claimed, query_airdrop_stage};
+use crate::query::auction::{query_auction_bid, query_auction_price, query_auction_status};
+use crate::query::automation::query_automation;
+use crate::query::basket::{query_basket_composition, query_basket_nav};
+use crate::query::bridge::{query_bridge_channel, query_bridge_channels};
+use crate::query::cdp::{
+    query_cdp_config, query_cdp_liquidations, query_cdp_position, query_cdp_positions,
+};
+use crate::query::curve::{query_curve_price, query_curve_state};
+use crate::query::cw20::{query_balance, query_marketing_info, query_minter, query_token_info};
+use crate::query::cw20_wrapper::query_cw20_wrapper;
+use crate::query::distribution::query_distribution_status;
+use crate::query::emission::query_pending_emissions;
+use crate::query::holders::{query_holder_count, query_holders, query_top_holders};
+use crate::query::hooks::query_hooks;
 use crate::query::info::query_info;
+use crate::query::migration::{query_migration_balance, query_migration_status};
+use crate::query::payment_stream::{query_payment_stream, query_payment_streams};
+use crate::query::raise::{
+    query_raise_contribution, query_raise_contributions, query_raise_status,
+};
+use crate::query::remote_mint::query_remote_transfers;
+use crate::query::sale::{query_sale_purchases, query_sale_status};
+use crate::query::scheduled_mint::query_scheduled_mints;
+use crate::query::staking::{query_staking_state, query_unbonding_claims};
+use crate::query::voucher::{query_voucher_nonce_used, query_voucher_signer};
+use crate::query::wrapped_asset::query_backing;
 use crate::query::{query_config, ReadonlyContext};
 use crate::state::storage::MANAGER;
 use crate::state::{
-    self, transfer_initial_balances, INITIAL_BALANCES_REPLY_ID, INITIAL_MINT_REPLY_ID,
+    self, transfer_initial_balances, AUTOMATION_REPLY_ID, HOOK_REPLY_ID, INITIAL_BALANCES_REPLY_ID,
+    INITIAL_MINT_REPLY_ID, REMOTE_TRANSFER_REPLY_ID,
 };
 use cosmwasm_std::{ensure_eq, entry_point, to_json_binary, Reply};
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
@@ -28,7 +106,7 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    Ok(state::init(Context { deps, env, info }, msg)?)
+    state::init(Context { deps, env, info }, msg)
 }
 
 #[entry_point]
@@ -40,35 +118,266 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     let ctx = Context { deps, env, info };
 
-    ensure_eq!(
-        ctx.info.sender,
-        MANAGER.load(ctx.deps.storage)?,
-        ContractError::NotAuthorized {
-            reason: "only manager can perform this action".to_owned()
-        }
-    );
+    if !msg.is_permissionless() {
+        ensure_eq!(
+            ctx.info.sender,
+            MANAGER.load(ctx.deps.storage)?,
+            ContractError::NotAuthorized {
+                reason: "only manager can perform this action".to_owned()
+            }
+        );
+    }
 
     match msg {
         ExecuteMsg::SetDenomMetadata { metadata } => exec_set_denom_metadata(ctx, metadata),
         ExecuteMsg::SetDenomAdmin { address } => exec_set_denom_admin(ctx, address),
         ExecuteMsg::RemoveDenomAdmin {} => exec_remove_denom_admin(ctx),
         ExecuteMsg::SetManager { address } => exec_set_manager(ctx, address),
+        ExecuteMsg::SetMintFee { mint_fee } => exec_set_mint_fee(ctx, mint_fee),
+        ExecuteMsg::SetMaxSupply { max_supply } => exec_set_max_supply(ctx, max_supply),
         ExecuteMsg::Mint { recipient, amount } => exec_mint(ctx, recipient, amount),
         ExecuteMsg::Burn { amount } => exec_burn(ctx, amount),
+        ExecuteMsg::SetCw20Wrapper { address } => exec_set_cw20_wrapper(ctx, address),
+        ExecuteMsg::Receive(msg) => exec_receive(ctx, msg),
+        ExecuteMsg::Unwrap {} => exec_unwrap(ctx),
+        ExecuteMsg::StartCw20Migration { token } => exec_start_cw20_migration(ctx, token),
+        ExecuteMsg::UploadMigrationSnapshot { balances } => {
+            exec_upload_migration_snapshot(ctx, balances)
+        },
+        ExecuteMsg::SnapshotCw20Balances { limit } => exec_snapshot_cw20_balances(ctx, limit),
+        ExecuteMsg::FinalizeCw20Migration {} => exec_finalize_cw20_migration(ctx),
+        ExecuteMsg::ClaimMigration {} => exec_claim_migration(ctx),
+        ExecuteMsg::PushMigration { limit } => exec_push_migration(ctx, limit),
+        ExecuteMsg::RegisterAirdrop {
+            merkle_root,
+            total_amount,
+            start,
+            expiration,
+        } => exec_register_airdrop(ctx, merkle_root, total_amount, start, expiration),
+        ExecuteMsg::ClaimAirdrop {
+            stage,
+            amount,
+            proof,
+        } => exec_claim_airdrop(ctx, stage, amount, proof),
+        ExecuteMsg::ClawbackUnclaimed { stage, recipient } => {
+            exec_clawback_unclaimed(ctx, stage, recipient)
+        },
+        ExecuteMsg::ContinueDistribution { limit } => exec_continue_distribution(ctx, limit),
+        ExecuteMsg::AppendInitialBalances { balances } => {
+            exec_append_initial_balances(ctx, balances)
+        },
+        ExecuteMsg::ConfigureSale {
+            price_denom,
+            price_per_token,
+            start,
+            end,
+            max_per_address,
+            hard_cap,
+            treasury,
+        } => exec_configure_sale(
+            ctx,
+            price_denom,
+            price_per_token,
+            start,
+            end,
+            max_per_address,
+            hard_cap,
+            treasury,
+        ),
+        ExecuteMsg::Buy { recipient } => exec_buy(ctx, recipient),
+        ExecuteMsg::ConfigureCurve {
+            reserve_denom,
+            curve_type,
+            entry_fee_bps,
+            exit_fee_bps,
+            treasury,
+        } => exec_configure_curve(
+            ctx,
+            reserve_denom,
+            curve_type,
+            entry_fee_bps,
+            exit_fee_bps,
+            treasury,
+        ),
+        ExecuteMsg::BuyFromCurve { recipient } => exec_buy_from_curve(ctx, recipient),
+        ExecuteMsg::SellToCurve {} => exec_sell_to_curve(ctx),
+        ExecuteMsg::ConfigureAuction {
+            reserve_denom,
+            start_price,
+            floor_price,
+            start,
+            end,
+            step_seconds,
+            supply,
+            treasury,
+        } => exec_configure_auction(
+            ctx,
+            reserve_denom,
+            start_price,
+            floor_price,
+            start,
+            end,
+            step_seconds,
+            supply,
+            treasury,
+        ),
+        ExecuteMsg::PlaceBid { recipient } => exec_place_bid(ctx, recipient),
+        ExecuteMsg::SettleAuction { limit } => exec_settle_auction(ctx, limit),
+        ExecuteMsg::ReclaimBid {} => exec_reclaim_bid(ctx),
+        ExecuteMsg::ConfigureRaise {
+            denom,
+            soft_cap,
+            hard_cap,
+            start,
+            end,
+            pricing,
+            treasury,
+        } => exec_configure_raise(
+            ctx, denom, soft_cap, hard_cap, start, end, pricing, treasury,
+        ),
+        ExecuteMsg::Contribute { recipient } => exec_contribute(ctx, recipient),
+        ExecuteMsg::Claim { round } => exec_claim(ctx, round),
+        ExecuteMsg::Refund { round } => exec_refund(ctx, round),
+        ExecuteMsg::WithdrawRaiseProceeds { round } => exec_withdraw_raise_proceeds(ctx, round),
+        ExecuteMsg::ConfigureWrappedAsset { underlying_denom } => {
+            exec_configure_wrapped_asset(ctx, underlying_denom)
+        },
+        ExecuteMsg::Deposit { recipient } => exec_deposit(ctx, recipient),
+        ExecuteMsg::Withdraw {} => exec_withdraw(ctx),
+        ExecuteMsg::ConfigureStaking {
+            bond_denom,
+            validators,
+            unbonding_period,
+        } => exec_configure_staking(ctx, bond_denom, validators, unbonding_period),
+        ExecuteMsg::Stake { recipient } => exec_stake(ctx, recipient),
+        ExecuteMsg::Compound {} => exec_compound(ctx),
+        ExecuteMsg::Unstake {} => exec_unstake(ctx),
+        ExecuteMsg::ClaimUnbonded {} => exec_claim_unbonded(ctx),
+        ExecuteMsg::ConfigureBasket {
+            components,
+            timelock_seconds,
+        } => exec_configure_basket(ctx, components, timelock_seconds),
+        ExecuteMsg::MintBasket { units, recipient } => exec_mint_basket(ctx, units, recipient),
+        ExecuteMsg::RedeemBasket {} => exec_redeem_basket(ctx),
+        ExecuteMsg::ProposeBasketRebalance { components } => {
+            exec_propose_basket_rebalance(ctx, components)
+        },
+        ExecuteMsg::ExecuteBasketRebalance {} => exec_execute_basket_rebalance(ctx),
+        ExecuteMsg::CancelBasketRebalance {} => exec_cancel_basket_rebalance(ctx),
+        ExecuteMsg::ConfigureCdp {
+            oracle,
+            collateral_denoms,
+            collateral_ratio,
+            liquidation_ratio,
+            liquidation_bonus,
+        } => exec_configure_cdp(
+            ctx,
+            oracle,
+            collateral_denoms,
+            collateral_ratio,
+            liquidation_ratio,
+            liquidation_bonus,
+        ),
+        ExecuteMsg::DepositCollateral {} => exec_deposit_collateral(ctx),
+        ExecuteMsg::WithdrawCollateral { collateral } => exec_withdraw_collateral(ctx, collateral),
+        ExecuteMsg::Borrow { amount } => exec_borrow(ctx, amount),
+        ExecuteMsg::Repay {} => exec_repay(ctx),
+        ExecuteMsg::Liquidate { owner } => exec_liquidate(ctx, owner),
+        ExecuteMsg::AddEmission {
+            recipient,
+            start,
+            schedule,
+            total,
+        } => exec_add_emission(ctx, recipient, start, schedule, total),
+        ExecuteMsg::RemoveEmission { id } => exec_remove_emission(ctx, id),
+        ExecuteMsg::Emit { limit } => exec_emit(ctx, limit),
+        ExecuteMsg::SetAutomation {
+            enabled,
+            max_actions,
+        } => exec_set_automation(ctx, enabled, max_actions),
+        ExecuteMsg::RegisterClock {} => exec_register_clock(ctx),
+        ExecuteMsg::RunAutomation {} => exec_run_automation(ctx),
+        ExecuteMsg::CreateStream {
+            recipient,
+            rate_per_second,
+            start,
+            end,
+        } => exec_create_stream(ctx, recipient, rate_per_second, start, end),
+        ExecuteMsg::WithdrawStream { id } => exec_withdraw_stream(ctx, id),
+        ExecuteMsg::CancelStream { id } => exec_cancel_stream(ctx, id),
+        ExecuteMsg::ScheduleMint {
+            recipient,
+            amount,
+            not_before,
+        } => exec_schedule_mint(ctx, recipient, amount, not_before),
+        ExecuteMsg::ExecuteDueMints { limit } => exec_execute_due_mints(ctx, limit),
+        ExecuteMsg::CancelScheduledMint { id } => exec_cancel_scheduled_mint(ctx, id),
+        ExecuteMsg::SetVoucherSigner { signer } => exec_set_voucher_signer(ctx, signer),
+        ExecuteMsg::RedeemVoucher {
+            recipient,
+            amount,
+            nonce,
+            expires,
+            signature,
+        } => exec_redeem_voucher(ctx, recipient, amount, nonce, expires, signature),
+        ExecuteMsg::MintAndCall {
+            contract,
+            amount,
+            msg,
+        } => exec_mint_and_call(ctx, contract, amount, msg),
+        ExecuteMsg::MintToRemote {
+            channel_id,
+            remote_recipient,
+            amount,
+            timeout,
+            memo,
+            refund_address,
+        } => exec_mint_to_remote(
+            ctx,
+            channel_id,
+            remote_recipient,
+            amount,
+            timeout,
+            memo,
+            refund_address,
+        ),
+        ExecuteMsg::SetBridgePeer {
+            connection_id,
+            port_id,
+            is_allowed,
+        } => exec_set_bridge_peer(ctx, connection_id, port_id, is_allowed),
+        ExecuteMsg::BridgeTransfer {
+            channel_id,
+            remote_recipient,
+            timeout,
+        } => exec_bridge_transfer(ctx, channel_id, remote_recipient, timeout),
+        ExecuteMsg::AddHook {
+            contract,
+            is_blocking,
+        } => exec_add_hook(ctx, contract, is_blocking),
+        ExecuteMsg::RemoveHook { contract } => exec_remove_hook(ctx, contract),
+        ExecuteMsg::EnableHolderTracking {} => exec_enable_holder_tracking(ctx),
+        ExecuteMsg::ReconcileHolder { address } => exec_reconcile_holder(ctx, address),
     }
 }
 
 #[entry_point]
 pub fn reply(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     reply: Reply,
 ) -> Result<Response, ContractError> {
     if reply.id == INITIAL_BALANCES_REPLY_ID {
         // Finish creating the new market contract, storing its address
         transfer_initial_balances(deps, reply)
+    } else if reply.id == AUTOMATION_REPLY_ID {
+        handle_automation_reply(deps, reply)
+    } else if reply.id == REMOTE_TRANSFER_REPLY_ID {
+        handle_remote_transfer_reply(deps, reply)
+    } else if reply.id == HOOK_REPLY_ID {
+        handle_hook_reply(deps, reply)
     } else if reply.id >= INITIAL_MINT_REPLY_ID {
-        transfer_minted_coins(deps, reply)
+        transfer_minted_coins(deps, env, reply)
     } else {
         Err(ContractError::NotAuthorized {
             reason: format!("unrecognized reply id {}", reply.id),
@@ -76,6 +385,23 @@ pub fn reply(
     }
 }
 
+#[entry_point]
+pub fn sudo(
+    deps: DepsMut,
+    env: Env,
+    msg: SudoMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::ClockEndBlock {} | SudoMsg::CronTick {} => sudo_end_block(deps, env),
+        SudoMsg::IbcLifecycleComplete(msg) => sudo_ibc_lifecycle(deps, env, msg),
+        SudoMsg::TrackBeforeSend { from, to, amount } => {
+            sudo_track_before_send(deps, env, from, to, amount)
+        },
+        // Transfers are only tracked, never blocked
+        SudoMsg::BlockBeforeSend { .. } => Ok(Response::new()),
+    }
+}
+
 #[entry_point]
 pub fn query(
     deps: Deps,
@@ -86,6 +412,81 @@ pub fn query(
     let result = match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(ctx)?),
         QueryMsg::Info {} => to_json_binary(&query_info(ctx)?),
+        QueryMsg::Cw20Wrapper {} => to_json_binary(&query_cw20_wrapper(ctx)?),
+        QueryMsg::TokenInfo {} => to_json_binary(&query_token_info(ctx)?),
+        QueryMsg::Balance { address } => to_json_binary(&query_balance(ctx, address)?),
+        QueryMsg::Minter {} => to_json_binary(&query_minter(ctx)?),
+        QueryMsg::MarketingInfo {} => to_json_binary(&query_marketing_info(ctx)?),
+        QueryMsg::MigrationStatus {} => to_json_binary(&query_migration_status(ctx)?),
+        QueryMsg::MigrationBalance { address } => {
+            to_json_binary(&query_migration_balance(ctx, address)?)
+        },
+        QueryMsg::AirdropStage { stage } => to_json_binary(&query_airdrop_stage(ctx, stage)?),
+        QueryMsg::AirdropClaimed { stage, address } => {
+            to_json_binary(&query_airdrop_claimed(ctx, stage, address)?)
+        },
+        QueryMsg::DistributionStatus {} => to_json_binary(&query_distribution_status(ctx)?),
+        QueryMsg::SaleStatus {} => to_json_binary(&query_sale_status(ctx)?),
+        QueryMsg::SalePurchases { address } => to_json_binary(&query_sale_purchases(ctx, address)?),
+        QueryMsg::CurvePrice { amount } => to_json_binary(&query_curve_price(ctx, amount)?),
+        QueryMsg::CurveState {} => to_json_binary(&query_curve_state(ctx)?),
+        QueryMsg::AuctionStatus {} => to_json_binary(&query_auction_status(ctx)?),
+        QueryMsg::AuctionPrice {} => to_json_binary(&query_auction_price(ctx)?),
+        QueryMsg::AuctionBid { address } => to_json_binary(&query_auction_bid(ctx, address)?),
+        QueryMsg::RaiseStatus { round } => to_json_binary(&query_raise_status(ctx, round)?),
+        QueryMsg::RaiseContribution { address, round } => {
+            to_json_binary(&query_raise_contribution(ctx, address, round)?)
+        },
+        QueryMsg::RaiseContributions {
+            round,
+            start_after,
+            limit,
+        } => to_json_binary(&query_raise_contributions(ctx, round, start_after, limit)?),
+        QueryMsg::Backing {} => to_json_binary(&query_backing(ctx)?),
+        QueryMsg::StakingState {} => to_json_binary(&query_staking_state(ctx)?),
+        QueryMsg::UnbondingClaims { address } => {
+            to_json_binary(&query_unbonding_claims(ctx, address)?)
+        },
+        QueryMsg::BasketComposition {} => to_json_binary(&query_basket_composition(ctx)?),
+        QueryMsg::BasketNav {} => to_json_binary(&query_basket_nav(ctx)?),
+        QueryMsg::CdpConfig {} => to_json_binary(&query_cdp_config(ctx)?),
+        QueryMsg::CdpPosition { address } => to_json_binary(&query_cdp_position(ctx, address)?),
+        QueryMsg::CdpPositions { start_after, limit } => {
+            to_json_binary(&query_cdp_positions(ctx, start_after, limit)?)
+        },
+        QueryMsg::CdpLiquidations { start_after, limit } => {
+            to_json_binary(&query_cdp_liquidations(ctx, start_after, limit)?)
+        },
+        QueryMsg::PendingEmissions {} => to_json_binary(&query_pending_emissions(ctx)?),
+        QueryMsg::Automation {} => to_json_binary(&query_automation(ctx)?),
+        QueryMsg::PaymentStream { id } => to_json_binary(&query_payment_stream(ctx, id)?),
+        QueryMsg::PaymentStreams {
+            recipient,
+            start_after,
+            limit,
+        } => to_json_binary(&query_payment_streams(ctx, recipient, start_after, limit)?),
+        QueryMsg::ScheduledMints { start_after, limit } => {
+            to_json_binary(&query_scheduled_mints(ctx, start_after, limit)?)
+        },
+        QueryMsg::VoucherSigner {} => to_json_binary(&query_voucher_signer(ctx)?),
+        QueryMsg::VoucherNonceUsed { nonce } => {
+            to_json_binary(&query_voucher_nonce_used(ctx, nonce)?)
+        },
+        QueryMsg::RemoteTransfers { start_after, limit } => {
+            to_json_binary(&query_remote_transfers(ctx, start_after, limit)?)
+        },
+        QueryMsg::BridgeChannel { channel_id } => {
+            to_json_binary(&query_bridge_channel(ctx, channel_id)?)
+        },
+        QueryMsg::BridgeChannels { start_after, limit } => {
+            to_json_binary(&query_bridge_channels(ctx, start_after, limit)?)
+        },
+        QueryMsg::Hooks {} => to_json_binary(&query_hooks(ctx)?),
+        QueryMsg::HolderCount {} => to_json_binary(&query_holder_count(ctx)?),
+        QueryMsg::Holders { start_after, limit } => {
+            to_json_binary(&query_holders(ctx, start_after, limit)?)
+        },
+        QueryMsg::TopHolders { limit } => to_json_binary(&query_top_holders(ctx, limit)?),
     }?;
     Ok(result)
 }
@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -6,6 +7,9 @@ pub enum ContractError {
   #[error("{0}")]
   Std(#[from] StdError),
 
+  #[error("{0}")]
+  Payment(#[from] PaymentError),
+
   #[error("NotAuthorized: {reason:?}")]
   NotAuthorized { reason: String },
 
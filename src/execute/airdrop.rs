@@ -0,0 +1,201 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::AirdropStage,
+        storage::{AIRDROP_CLAIMS, AIRDROP_LATEST_STAGE, AIRDROP_STAGES},
+    },
+};
+use cosmwasm_std::{attr, Addr, Response, StdError, Timestamp, Uint128};
+use sha2::{Digest, Sha256};
+
+use super::{mint::build_mint_submsg, Context};
+
+pub fn exec_register_airdrop(
+    ctx: Context,
+    merkle_root: String,
+    total_amount: Uint128,
+    start: Option<Timestamp>,
+    expiration: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+
+    decode_hash(&merkle_root)?;
+
+    if total_amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "airdrop total amount must be non-zero".to_owned(),
+        });
+    }
+
+    if let Some(expiration) = expiration {
+        if expiration <= start.unwrap_or(env.block.time) {
+            return Err(ContractError::ValidationError {
+                reason: "airdrop must expire after it starts".to_owned(),
+            });
+        }
+    }
+
+    let stage = AIRDROP_LATEST_STAGE
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+
+    AIRDROP_LATEST_STAGE.save(deps.storage, &stage)?;
+    AIRDROP_STAGES.save(
+        deps.storage,
+        stage,
+        &AirdropStage {
+            merkle_root: merkle_root.to_lowercase(),
+            total_amount,
+            claimed_amount: Uint128::zero(),
+            start,
+            expiration,
+            closed: false,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "register_airdrop"),
+        attr("stage", stage.to_string()),
+        attr("merkle_root", merkle_root),
+        attr("total_amount", total_amount.to_string()),
+    ]))
+}
+
+pub fn exec_claim_airdrop(
+    ctx: Context,
+    stage: u32,
+    amount: Uint128,
+    proof: Vec<String>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let mut airdrop = AIRDROP_STAGES.load(deps.storage, stage)?;
+
+    if !airdrop.is_active(env.block.time) {
+        return Err(ContractError::ValidationError {
+            reason: format!("airdrop stage {} is not active", stage),
+        });
+    }
+
+    if AIRDROP_CLAIMS.has(deps.storage, (stage, &info.sender)) {
+        return Err(ContractError::ValidationError {
+            reason: "airdrop already claimed".to_owned(),
+        });
+    }
+
+    verify_proof(&airdrop.merkle_root, &info.sender, amount, &proof)?;
+
+    airdrop.claimed_amount = airdrop
+        .claimed_amount
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    if airdrop.claimed_amount > airdrop.total_amount {
+        return Err(ContractError::ValidationError {
+            reason: "airdrop total amount exceeded".to_owned(),
+        });
+    }
+
+    AIRDROP_STAGES.save(deps.storage, stage, &airdrop)?;
+    AIRDROP_CLAIMS.save(deps.storage, (stage, &info.sender), &amount)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "claim_airdrop"),
+            attr("stage", stage.to_string()),
+            attr("recipient", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessage(build_mint_submsg(deps.storage, &env, &info.sender, amount)?))
+}
+
+/// Close an expired stage to further claims, optionally minting whatever
+/// remains unclaimed to the given recipient.
+pub fn exec_clawback_unclaimed(
+    ctx: Context,
+    stage: u32,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let mut airdrop = AIRDROP_STAGES.load(deps.storage, stage)?;
+
+    if airdrop.closed {
+        return Err(ContractError::ValidationError {
+            reason: format!("airdrop stage {} already closed", stage),
+        });
+    }
+
+    if airdrop.expiration.is_none() || !airdrop.is_expired(env.block.time) {
+        return Err(ContractError::ValidationError {
+            reason: format!("airdrop stage {} has not expired", stage),
+        });
+    }
+
+    let unclaimed_amount = airdrop.total_amount - airdrop.claimed_amount;
+
+    airdrop.closed = true;
+    AIRDROP_STAGES.save(deps.storage, stage, &airdrop)?;
+
+    let mut resp = Response::new().add_attributes(vec![
+        attr("action", "clawback_unclaimed"),
+        attr("stage", stage.to_string()),
+        attr("unclaimed_amount", unclaimed_amount.to_string()),
+    ]);
+
+    if let Some(recipient) = recipient {
+        let recipient = deps.api.addr_validate(recipient.as_str())?;
+        if !unclaimed_amount.is_zero() {
+            resp = resp
+                .add_attribute("recipient", recipient.to_string())
+                .add_submessage(build_mint_submsg(
+                    deps.storage,
+                    &env,
+                    &recipient,
+                    unclaimed_amount,
+                )?);
+        }
+    }
+
+    Ok(resp)
+}
+
+/// Verify a sha256 merkle proof for the leaf `sha256("{address}{amount}")`,
+/// hashing each pair of nodes in sorted order.
+fn verify_proof(
+    merkle_root: &str,
+    address: &Addr,
+    amount: Uint128,
+    proof: &[String],
+) -> Result<(), ContractError> {
+    let root = decode_hash(merkle_root)?;
+    let mut hash: [u8; 32] = Sha256::digest(format!("{}{}", address, amount).as_bytes()).into();
+
+    for node in proof.iter() {
+        let node = decode_hash(node)?;
+        let mut hasher = Sha256::new();
+        if hash < node {
+            hasher.update(hash);
+            hasher.update(node);
+        } else {
+            hasher.update(node);
+            hasher.update(hash);
+        }
+        hash = hasher.finalize().into();
+    }
+
+    if hash != root {
+        return Err(ContractError::NotAuthorized {
+            reason: "invalid merkle proof".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+fn decode_hash(hex_str: &str) -> Result<[u8; 32], ContractError> {
+    let mut buf = [0u8; 32];
+    hex::decode_to_slice(hex_str, &mut buf).map_err(|e| ContractError::ValidationError {
+        reason: format!("invalid sha256 hash {}: {}", hex_str, e),
+    })?;
+    Ok(buf)
+}
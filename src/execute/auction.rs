@@ -0,0 +1,366 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{Auction, AuctionState},
+        storage::{AUCTION, AUCTION_BIDS, AUCTION_STATE, DENOM_METADATA, MANAGER},
+        AUCTION_RECLAIM_DELAY_SECONDS, DEFAULT_LIMIT, MAX_LIMIT,
+    },
+};
+use cosmwasm_std::{
+    attr, Addr, BankMsg, Coin, Order, OverflowError, OverflowOperation, Response, StdError,
+    StdResult, Storage, SubMsg, Timestamp, Uint128, Uint256,
+};
+use cw_storage_plus::Bound;
+use cw_utils::must_pay;
+
+use super::{
+    mint::{
+        build_mint_submsg, ensure_within_max_supply, mint_fee_for, remaining_supply,
+        take_from_budget,
+    },
+    Context,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn exec_configure_auction(
+    ctx: Context,
+    reserve_denom: String,
+    start_price: Uint128,
+    floor_price: Uint128,
+    start: Timestamp,
+    end: Timestamp,
+    step_seconds: Option<u64>,
+    supply: Uint128,
+    treasury: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+
+    if let Some(state) = AUCTION_STATE.may_load(deps.storage)? {
+        if !state.total_committed.is_zero() && !state.settled {
+            return Err(ContractError::ValidationError {
+                reason: "cannot reconfigure an unsettled auction".to_owned(),
+            });
+        }
+    }
+
+    if floor_price.is_zero() || start_price < floor_price {
+        return Err(ContractError::ValidationError {
+            reason: "start price must be at least the non-zero floor price".to_owned(),
+        });
+    }
+
+    if end <= start {
+        return Err(ContractError::ValidationError {
+            reason: "auction must end after it starts".to_owned(),
+        });
+    }
+
+    if supply.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "auction supply must be non-zero".to_owned(),
+        });
+    }
+
+    let fee = mint_fee_for(deps.storage, supply)?
+        .map(|fee| fee.amount)
+        .unwrap_or_default();
+    ensure_within_max_supply(
+        deps.storage,
+        supply
+            .checked_add(fee)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?,
+    )?;
+
+    let treasury = match treasury {
+        Some(addr) => deps.api.addr_validate(addr.as_str())?,
+        None => MANAGER.load(deps.storage)?,
+    };
+
+    let decimals = DENOM_METADATA.load(deps.storage)?.decimals;
+    let token_unit = Uint128::from(10u128)
+        .checked_pow(decimals)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    // Bids are keyed by round, so bids from a previous auction are left as is
+    let round = AUCTION
+        .may_load(deps.storage)?
+        .map(|auction| auction.round + 1)
+        .unwrap_or(1);
+
+    AUCTION.save(
+        deps.storage,
+        &Auction {
+            round,
+            reserve_denom: reserve_denom.to_owned(),
+            start_price,
+            floor_price,
+            start,
+            end,
+            step_seconds,
+            supply,
+            token_unit,
+            treasury: treasury.to_owned(),
+        },
+    )?;
+    AUCTION_STATE.save(deps.storage, &AuctionState::default())?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "configure_auction"),
+        attr("round", round.to_string()),
+        attr("reserve_denom", reserve_denom),
+        attr("supply", supply.to_string()),
+        attr("treasury", treasury.to_string()),
+    ]))
+}
+
+/// Commit reserve funds to the auction. Whatever exceeds the amount needed to
+/// buy out the remaining supply at the current price is refunded.
+pub fn exec_place_bid(
+    ctx: Context,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let recipient = ctx.recipient_or_sender(recipient)?;
+    let Context { deps, env, info } = ctx;
+    let auction = load_auction(deps.storage)?;
+    let mut state = AUCTION_STATE.load(deps.storage)?;
+    let now = env.block.time;
+
+    if now < auction.start || auction.is_closed(now, state.total_committed) {
+        return Err(ContractError::ValidationError {
+            reason: "auction is not open".to_owned(),
+        });
+    }
+
+    let payment = must_pay(&info, &auction.reserve_denom)?;
+    let capacity = auction
+        .supply
+        .multiply_ratio(auction.price(now), auction.token_unit)
+        .saturating_sub(state.total_committed);
+    let accepted = payment.min(capacity);
+    let refund = payment - accepted;
+
+    if accepted.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "auction is sold out".to_owned(),
+        });
+    }
+
+    let mut bid = AUCTION_BIDS
+        .may_load(deps.storage, (auction.round, &recipient))?
+        .unwrap_or_default();
+
+    if bid.committed.is_zero() {
+        state.bidder_count = state.bidder_count.checked_add(1).ok_or_else(|| {
+            ContractError::Std(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+                state.bidder_count,
+                1,
+            )))
+        })?;
+    }
+    bid.committed = bid
+        .committed
+        .checked_add(accepted)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.total_committed = state
+        .total_committed
+        .checked_add(accepted)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    AUCTION_BIDS.save(deps.storage, (auction.round, &recipient), &bid)?;
+    AUCTION_STATE.save(deps.storage, &state)?;
+
+    let mut resp = Response::new().add_attributes(vec![
+        attr("action", "place_bid"),
+        attr("bidder", info.sender.to_string()),
+        attr("recipient", recipient.to_string()),
+        attr("amount", accepted.to_string()),
+        attr("price", auction.price(now).to_string()),
+    ]);
+
+    if !refund.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(refund.u128(), auction.reserve_denom.to_owned())],
+        });
+    }
+
+    Ok(resp)
+}
+
+/// Settle the next page of bids at the clearing price, minting tokens to each
+/// bidder, refunding any excess and sending proceeds to the treasury
+pub fn exec_settle_auction(
+    ctx: Context,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let auction = load_auction(deps.storage)?;
+    let mut state = AUCTION_STATE.load(deps.storage)?;
+
+    if state.settled {
+        return Err(ContractError::ValidationError {
+            reason: "auction already settled".to_owned(),
+        });
+    }
+
+    if !auction.is_closed(env.block.time, state.total_committed) {
+        return Err(ContractError::ValidationError {
+            reason: "auction is still open".to_owned(),
+        });
+    }
+
+    let clearing_price = *state
+        .clearing_price
+        .get_or_insert_with(|| auction.clearing_price(state.total_committed));
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let page = AUCTION_BIDS
+        .prefix(auction.round)
+        .range(
+            deps.storage,
+            state.settle_cursor.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut submsgs: Vec<SubMsg> = Vec::with_capacity(page.len() * 2 + 1);
+    let mut page_proceeds = Uint128::zero();
+    // If the max supply was lowered since the auction was configured, bids
+    // are filled as far as it allows and refunded for the rest
+    let mut budget = remaining_supply(deps.storage)?;
+
+    for (bidder, mut bid) in page.into_iter() {
+        state.settle_cursor = Some(bidder.to_owned());
+
+        // Already refunded through `ReclaimBid`
+        if bid.settled {
+            continue;
+        }
+
+        bid.amount = take_from_budget(
+            deps.storage,
+            bid.committed
+                .multiply_ratio(auction.token_unit, clearing_price),
+            &mut budget,
+        )?;
+
+        // Round the cost up so that bidders never underpay
+        let mut cost = bid
+            .amount
+            .multiply_ratio(clearing_price, auction.token_unit);
+        if Uint256::from(cost) * Uint256::from(auction.token_unit)
+            < bid.amount.full_mul(clearing_price)
+        {
+            cost += Uint128::one();
+        }
+        let cost = cost.min(bid.committed);
+
+        bid.refund = bid.committed - cost;
+        bid.settled = true;
+
+        if !bid.amount.is_zero() {
+            submsgs.push(build_mint_submsg(deps.storage, &env, &bidder, bid.amount)?);
+        }
+        if !bid.refund.is_zero() {
+            submsgs.push(SubMsg::new(BankMsg::Send {
+                to_address: bidder.to_string(),
+                amount: vec![Coin::new(
+                    bid.refund.u128(),
+                    auction.reserve_denom.to_owned(),
+                )],
+            }));
+        }
+
+        page_proceeds = page_proceeds
+            .checked_add(cost)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        state.amount_sold = state
+            .amount_sold
+            .checked_add(bid.amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        AUCTION_BIDS.save(deps.storage, (auction.round, &bidder), &bid)?;
+    }
+
+    if !page_proceeds.is_zero() {
+        submsgs.push(SubMsg::new(BankMsg::Send {
+            to_address: auction.treasury.to_string(),
+            amount: vec![Coin::new(
+                page_proceeds.u128(),
+                auction.reserve_denom.to_owned(),
+            )],
+        }));
+    }
+
+    state.proceeds = state
+        .proceeds
+        .checked_add(page_proceeds)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.settled = AUCTION_BIDS
+        .prefix(auction.round)
+        .keys(
+            deps.storage,
+            state.settle_cursor.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .next()
+        .is_none();
+
+    AUCTION_STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "settle_auction"),
+            attr("clearing_price", clearing_price.to_string()),
+            attr("proceeds", page_proceeds.to_string()),
+            attr("settled", state.settled.to_string()),
+        ])
+        .add_submessages(submsgs))
+}
+
+/// Refund the sender's whole bid when settlement has not reached it within
+/// `AUCTION_RECLAIM_DELAY_SECONDS` of the auction ending
+pub fn exec_reclaim_bid(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let auction = load_auction(deps.storage)?;
+    let state = AUCTION_STATE.load(deps.storage)?;
+
+    if state.settled || env.block.time < auction.end.plus_seconds(AUCTION_RECLAIM_DELAY_SECONDS) {
+        return Err(ContractError::ValidationError {
+            reason: "bids can only be reclaimed from an auction left unsettled".to_owned(),
+        });
+    }
+
+    let mut bid = AUCTION_BIDS
+        .may_load(deps.storage, (auction.round, &info.sender))?
+        .filter(|bid| !bid.settled)
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "no unsettled bid to reclaim".to_owned(),
+        })?;
+
+    bid.refund = bid.committed;
+    bid.settled = true;
+    AUCTION_BIDS.save(deps.storage, (auction.round, &info.sender), &bid)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "reclaim_bid"),
+            attr("bidder", info.sender.to_string()),
+            attr("refund", bid.refund.to_string()),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(bid.refund.u128(), auction.reserve_denom)],
+        }))
+}
+
+pub fn load_auction(storage: &dyn Storage) -> Result<Auction, ContractError> {
+    AUCTION
+        .may_load(storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "no auction configured".to_owned(),
+        })
+}
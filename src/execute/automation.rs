@@ -0,0 +1,143 @@
+use crate::{
+    error::ContractError,
+    msg::ExecuteMsg,
+    state::{
+        models::Automation,
+        storage::{AUTOMATION, AUTOMATION_EMISSION_CURSOR},
+        AUTOMATION_REPLY_ID, DEFAULT_AUTOMATION_MAX_ACTIONS,
+    },
+    tf::juno::clock::MsgRegisterClockContract,
+};
+use cosmwasm_std::{
+    attr, to_json_binary, DepsMut, Env, Reply, Response, SubMsg, SubMsgResult, WasmMsg,
+};
+
+use super::{
+    emission::emit_streams, mint::remaining_supply, scheduled_mint::execute_due_mints, Context,
+};
+
+pub fn exec_set_automation(
+    ctx: Context,
+    enabled: bool,
+    max_actions: Option<u32>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let max_actions = max_actions.unwrap_or(DEFAULT_AUTOMATION_MAX_ACTIONS);
+
+    if max_actions == 0 {
+        return Err(ContractError::ValidationError {
+            reason: "max_actions must be non-zero".to_owned(),
+        });
+    }
+
+    AUTOMATION.save(
+        deps.storage,
+        &Automation {
+            enabled,
+            max_actions,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_automation"),
+        attr("enabled", enabled.to_string()),
+        attr("max_actions", max_actions.to_string()),
+    ]))
+}
+
+/// Register with Juno's clock module to receive `ClockEndBlock` every block.
+/// The contract must be its own admin or creator for this to succeed.
+pub fn exec_register_clock(ctx: Context) -> Result<Response, ContractError> {
+    let Context { env, .. } = ctx;
+    Ok(Response::new()
+        .add_attribute("action", "register_clock")
+        .add_message(MsgRegisterClockContract {
+            sender_address: env.contract.address.to_string(),
+            contract_address: env.contract.address.to_string(),
+        }))
+}
+
+/// Process one block's worth of scheduled work
+pub fn exec_run_automation(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+
+    if info.sender != env.contract.address {
+        return Err(ContractError::NotAuthorized {
+            reason: "automation can only be run by this contract".to_owned(),
+        });
+    }
+
+    let automation = AUTOMATION.load(deps.storage)?;
+    let cursor = AUTOMATION_EMISSION_CURSOR.may_load(deps.storage)?;
+    // Emissions and due mints share the supply remaining under the cap
+    let mut budget = remaining_supply(deps.storage)?;
+    let (submsgs, total_emitted, cursor) = emit_streams(
+        deps.storage,
+        &env,
+        cursor,
+        automation.max_actions as usize,
+        &mut budget,
+    )?;
+
+    match cursor {
+        Some(id) => AUTOMATION_EMISSION_CURSOR.save(deps.storage, &id)?,
+        None => AUTOMATION_EMISSION_CURSOR.remove(deps.storage),
+    }
+
+    let (due_mint_submsgs, deferred) = execute_due_mints(
+        deps.storage,
+        &env,
+        automation.max_actions as usize,
+        &mut budget,
+    )?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "run_automation"),
+            attr("emissions", submsgs.len().to_string()),
+            attr("amount_emitted", total_emitted.to_string()),
+            attr("scheduled_mints", due_mint_submsgs.len().to_string()),
+            attr("scheduled_mints_deferred", deferred.to_string()),
+        ])
+        .add_submessages(submsgs)
+        .add_submessages(due_mint_submsgs))
+}
+
+/// Handle an end-block sudo message. Errors here would get the contract
+/// unregistered from the clock module, so work runs in a self-call whose
+/// failure is caught by `handle_automation_reply`.
+pub fn sudo_end_block(
+    deps: DepsMut,
+    env: Env,
+) -> Result<Response, ContractError> {
+    let is_enabled = AUTOMATION
+        .may_load(deps.storage)?
+        .map(|a| a.enabled)
+        .unwrap_or(false);
+
+    if !is_enabled {
+        return Ok(Response::new());
+    }
+
+    Ok(Response::new().add_submessage(SubMsg::reply_on_error(
+        WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::RunAutomation {})?,
+            funds: vec![],
+        },
+        AUTOMATION_REPLY_ID,
+    )))
+}
+
+/// Swallow a failed automation run, whose state changes were rolled back
+pub fn handle_automation_reply(
+    _deps: DepsMut,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    match reply.result {
+        SubMsgResult::Err(e) => Ok(Response::new()
+            .add_attribute("action", "automation_failed")
+            .add_attribute("error", e)),
+        SubMsgResult::Ok(_) => Ok(Response::new()),
+    }
+}
@@ -0,0 +1,340 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{Basket, BasketComponent, BasketRebalance},
+        storage::{BASKET, BASKET_REBALANCE, BASKET_STATE, DENOM_METADATA, FULL_DENOM},
+    },
+};
+use cosmwasm_std::{attr, Addr, BankMsg, Coin, Deps, Response, StdError, Storage, Uint128};
+use cw_utils::must_pay;
+
+use super::{burn::build_burn_submsgs, format_coins, mint::build_backed_mint_submsg, Context};
+
+pub fn exec_configure_basket(
+    ctx: Context,
+    components: Vec<BasketComponent>,
+    timelock_seconds: u64,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let state = BASKET_STATE.may_load(deps.storage)?.unwrap_or_default();
+
+    // Once tokens exist, composition changes must go through the timelock
+    if !state.supply.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "basket has supply; use a rebalance proposal".to_owned(),
+        });
+    }
+
+    validate_components(deps.as_ref(), &components)?;
+
+    let decimals = DENOM_METADATA.load(deps.storage)?.decimals;
+    let token_unit = Uint128::from(10u128)
+        .checked_pow(decimals)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    BASKET.save(
+        deps.storage,
+        &Basket {
+            components: components.to_owned(),
+            token_unit,
+            timelock_seconds,
+        },
+    )?;
+    BASKET_STATE.save(deps.storage, &state)?;
+    BASKET_REBALANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "configure_basket"),
+        attr("components", format_components(&components)),
+        attr("timelock_seconds", timelock_seconds.to_string()),
+    ]))
+}
+
+/// Mint whole basket tokens in exchange for exactly the required components
+pub fn exec_mint_basket(
+    ctx: Context,
+    units: Uint128,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let recipient = ctx.recipient_or_sender(recipient)?;
+    let Context { deps, env, info } = ctx;
+    let basket = load_basket(deps.storage)?;
+
+    if units.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "units must be non-zero".to_owned(),
+        });
+    }
+
+    let required = basket.required_funds(units)?;
+    let is_exact =
+        info.funds.len() == required.len() && required.iter().all(|c| info.funds.contains(c));
+    if !is_exact {
+        return Err(ContractError::ValidationError {
+            reason: format!("expected funds {}", format_coins(&required)),
+        });
+    }
+
+    let amount = units
+        .checked_mul(basket.token_unit)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    BASKET_STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
+        state.supply = state
+            .supply
+            .checked_add(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        for coin in required.iter() {
+            match state.holdings.iter_mut().find(|h| h.denom == coin.denom) {
+                Some(holding) => {
+                    holding.amount = holding
+                        .amount
+                        .checked_add(coin.amount)
+                        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+                },
+                None => state.holdings.push(coin.to_owned()),
+            }
+        }
+        Ok(state)
+    })?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "mint_basket"),
+            attr("recipient", recipient.to_string()),
+            attr("units", units.to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessage(build_backed_mint_submsg(
+            deps.storage,
+            &env,
+            &recipient,
+            amount,
+        )?))
+}
+
+/// Burn attached basket tokens and return their pro-rata share of the
+/// holdings deposited by basket mints
+pub fn exec_redeem_basket(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    load_basket(deps.storage)?;
+
+    let amount = must_pay(&info, &FULL_DENOM.load(deps.storage)?)?;
+    let mut state = BASKET_STATE.load(deps.storage)?;
+
+    // Tokens minted any other way have no claim on the holdings
+    if amount > state.supply {
+        return Err(ContractError::ValidationError {
+            reason: "amount exceeds basket-minted supply".to_owned(),
+        });
+    }
+
+    let mut payout: Vec<Coin> = vec![];
+    for holding in state.holdings.iter_mut() {
+        let share = holding.amount.multiply_ratio(amount, state.supply);
+        if !share.is_zero() {
+            holding.amount -= share;
+            payout.push(Coin::new(share.u128(), holding.denom.to_owned()));
+        }
+    }
+    state.holdings.retain(|h| !h.amount.is_zero());
+
+    if payout.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "redemption too small".to_owned(),
+        });
+    }
+
+    state.supply -= amount;
+    BASKET_STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "redeem_basket"),
+            attr("recipient", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+            attr("payout", format_coins(&payout)),
+        ])
+        .add_submessages(build_burn_submsgs(
+            deps.storage,
+            &env,
+            &info.sender,
+            amount,
+        )?)
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: payout,
+        }))
+}
+
+pub fn exec_propose_basket_rebalance(
+    ctx: Context,
+    components: Vec<BasketComponent>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let basket = load_basket(deps.storage)?;
+
+    validate_components(deps.as_ref(), &components)?;
+
+    let executable_at = env.block.time.plus_seconds(basket.timelock_seconds);
+
+    BASKET_REBALANCE.save(
+        deps.storage,
+        &BasketRebalance {
+            components: components.to_owned(),
+            executable_at,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "propose_basket_rebalance"),
+        attr("components", format_components(&components)),
+        attr("executable_at", executable_at.to_string()),
+    ]))
+}
+
+/// Apply a rebalance proposal once its timelock has passed. Holdings are
+/// settled against the new composition: the executor attaches each
+/// component the outstanding supply is short of and receives the surplus.
+pub fn exec_execute_basket_rebalance(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let mut basket = load_basket(deps.storage)?;
+    let rebalance =
+        BASKET_REBALANCE
+            .may_load(deps.storage)?
+            .ok_or_else(|| ContractError::ValidationError {
+                reason: "no pending rebalance".to_owned(),
+            })?;
+
+    if env.block.time < rebalance.executable_at {
+        return Err(ContractError::ValidationError {
+            reason: format!("rebalance timelocked until {}", rebalance.executable_at),
+        });
+    }
+
+    basket.components = rebalance.components;
+    let mut state = BASKET_STATE.load(deps.storage)?;
+    let targets = basket.holdings_for(state.supply)?;
+
+    let mut shortfall: Vec<Coin> = vec![];
+    for target in targets.iter() {
+        let held = state
+            .holdings
+            .iter()
+            .find(|h| h.denom == target.denom)
+            .map(|h| h.amount)
+            .unwrap_or_default();
+        if target.amount > held {
+            shortfall.push(Coin::new(
+                (target.amount - held).u128(),
+                target.denom.to_owned(),
+            ));
+        }
+    }
+    let mut surplus: Vec<Coin> = vec![];
+    for holding in state.holdings.iter() {
+        let target = targets
+            .iter()
+            .find(|t| t.denom == holding.denom)
+            .map(|t| t.amount)
+            .unwrap_or_default();
+        if holding.amount > target {
+            surplus.push(Coin::new(
+                (holding.amount - target).u128(),
+                holding.denom.to_owned(),
+            ));
+        }
+    }
+
+    let is_exact =
+        info.funds.len() == shortfall.len() && shortfall.iter().all(|c| info.funds.contains(c));
+    if !is_exact {
+        return Err(ContractError::ValidationError {
+            reason: format!("expected funds {}", format_coins(&shortfall)),
+        });
+    }
+
+    state.holdings = targets;
+    BASKET_STATE.save(deps.storage, &state)?;
+    BASKET.save(deps.storage, &basket)?;
+    BASKET_REBALANCE.remove(deps.storage);
+
+    let mut resp = Response::new().add_attributes(vec![
+        attr("action", "execute_basket_rebalance"),
+        attr("components", format_components(&basket.components)),
+    ]);
+    if !shortfall.is_empty() {
+        resp = resp.add_attribute("shortfall", format_coins(&shortfall));
+    }
+    if !surplus.is_empty() {
+        resp = resp.add_attribute("surplus", format_coins(&surplus));
+        resp = resp.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: surplus,
+        });
+    }
+    Ok(resp)
+}
+
+pub fn exec_cancel_basket_rebalance(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+
+    if BASKET_REBALANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::ValidationError {
+            reason: "no pending rebalance".to_owned(),
+        });
+    }
+
+    BASKET_REBALANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_basket_rebalance"))
+}
+
+pub fn load_basket(storage: &dyn Storage) -> Result<Basket, ContractError> {
+    BASKET
+        .may_load(storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "basket not configured".to_owned(),
+        })
+}
+
+fn validate_components(
+    deps: Deps,
+    components: &[BasketComponent],
+) -> Result<(), ContractError> {
+    if components.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "basket must have at least one component".to_owned(),
+        });
+    }
+
+    let full_denom = FULL_DENOM.load(deps.storage)?;
+
+    for (i, c) in components.iter().enumerate() {
+        if c.amount.is_zero() {
+            return Err(ContractError::ValidationError {
+                reason: format!("component {} has zero amount", c.denom),
+            });
+        }
+        if c.denom == full_denom {
+            return Err(ContractError::ValidationError {
+                reason: "basket cannot contain its own denom".to_owned(),
+            });
+        }
+        if components[..i].iter().any(|other| other.denom == c.denom) {
+            return Err(ContractError::ValidationError {
+                reason: format!("duplicate component {}", c.denom),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn format_components(components: &[BasketComponent]) -> String {
+    components
+        .iter()
+        .map(|c| format!("{}{}", c.amount, c.denom))
+        .collect::<Vec<_>>()
+        .join(",")
+}
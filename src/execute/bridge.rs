@@ -0,0 +1,161 @@
+use crate::{
+    error::ContractError,
+    msg::BridgePacket,
+    state::{
+        models::BridgeChannel,
+        storage::{BRIDGE_CHANNELS, BRIDGE_PEERS, FULL_DENOM},
+        DEFAULT_IBC_TIMEOUT_SECONDS,
+    },
+};
+use cosmwasm_std::{
+    attr, to_json_binary, Addr, Empty, Env, IbcMsg, IbcTimeout, Response, StdError, Storage,
+    SubMsg, Uint128,
+};
+use cw_utils::must_pay;
+
+use super::{
+    burn::build_burn_submsgs,
+    mint::{build_backed_mint_submsg, ensure_within_max_supply},
+    Context,
+};
+
+/// Allow or disallow a counterparty port on a connection to open bridge
+/// channels to this contract
+pub fn exec_set_bridge_peer(
+    ctx: Context,
+    connection_id: String,
+    port_id: String,
+    is_allowed: bool,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let key = (connection_id.as_str(), port_id.as_str());
+
+    if is_allowed {
+        BRIDGE_PEERS.save(deps.storage, key, &Empty {})?;
+    } else {
+        BRIDGE_PEERS.remove(deps.storage, key);
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_bridge_peer"),
+        attr("connection_id", connection_id),
+        attr("port_id", port_id),
+        attr("is_allowed", is_allowed.to_string()),
+    ]))
+}
+
+pub fn exec_bridge_transfer(
+    ctx: Context,
+    channel_id: String,
+    remote_recipient: String,
+    timeout: Option<u64>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let amount = must_pay(&info, &FULL_DENOM.load(deps.storage)?)?;
+    let mut channel = load_open_channel(deps.storage, &channel_id)?;
+
+    if remote_recipient.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "remote_recipient is required".to_owned(),
+        });
+    }
+
+    channel.amount_sent = channel
+        .amount_sent
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    BRIDGE_CHANNELS.save(deps.storage, &channel_id, &channel)?;
+
+    let timeout = env
+        .block
+        .time
+        .plus_seconds(timeout.unwrap_or(DEFAULT_IBC_TIMEOUT_SECONDS));
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "bridge_transfer"),
+            attr("channel_id", channel_id.to_owned()),
+            attr("sender", info.sender.to_string()),
+            attr("remote_recipient", remote_recipient.to_owned()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessages(build_burn_submsgs(
+            deps.storage,
+            &env,
+            &info.sender,
+            amount,
+        )?)
+        .add_message(IbcMsg::SendPacket {
+            channel_id,
+            data: to_json_binary(&BridgePacket {
+                sender: info.sender.to_string(),
+                recipient: remote_recipient,
+                amount,
+            })?,
+            timeout: IbcTimeout::with_timestamp(timeout),
+        }))
+}
+
+/// Mint tokens burned on the counterparty chain to the packet's recipient
+pub fn receive_bridge_packet(
+    storage: &mut dyn Storage,
+    env: &Env,
+    recipient: &Addr,
+    channel_id: &str,
+    amount: Uint128,
+) -> Result<SubMsg, ContractError> {
+    let mut channel = load_open_channel(storage, channel_id)?;
+
+    // An error in the mint reply would abort the receive without writing an
+    // ack, so the max supply the reply checks is enforced here, where an
+    // error is acked back to the sender
+    ensure_within_max_supply(storage, amount)?;
+
+    channel.amount_received = channel
+        .amount_received
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    // Saved last so that a failed receive, acked as an error, leaves no trace
+    let submsg = build_backed_mint_submsg(storage, env, recipient, amount)?;
+    BRIDGE_CHANNELS.save(storage, channel_id, &channel)?;
+
+    Ok(submsg)
+}
+
+/// Re-mint tokens to the sender of a packet that failed or timed out
+pub fn refund_bridge_packet(
+    storage: &mut dyn Storage,
+    env: &Env,
+    channel_id: &str,
+    packet: &BridgePacket,
+) -> Result<SubMsg, ContractError> {
+    let mut channel = BRIDGE_CHANNELS.load(storage, channel_id)?;
+
+    channel.amount_sent = channel
+        .amount_sent
+        .checked_sub(packet.amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    BRIDGE_CHANNELS.save(storage, channel_id, &channel)?;
+
+    build_backed_mint_submsg(
+        storage,
+        env,
+        &Addr::unchecked(&packet.sender),
+        packet.amount,
+    )
+}
+
+fn load_open_channel(
+    storage: &dyn Storage,
+    channel_id: &str,
+) -> Result<BridgeChannel, ContractError> {
+    BRIDGE_CHANNELS
+        .may_load(storage, channel_id)?
+        .filter(|channel| channel.is_open)
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: format!("bridge channel {} is not open", channel_id),
+        })
+}
@@ -2,24 +2,48 @@ use crate::{
     error::ContractError,
     state::storage::{AMOUNT_BURNED, FACTORY, FULL_DENOM},
 };
-use cosmwasm_std::{attr, Response, StdError, Uint128};
+use cosmwasm_std::{attr, Addr, Env, Response, StdError, Storage, SubMsg, Uint128};
 
-use super::Context;
+use super::{hooks::build_burn_hook_submsgs, Context};
 
 pub fn exec_burn(
     ctx: Context,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
     let Context { deps, env, .. } = ctx;
-    let factory = FACTORY.load(deps.storage)?;
-    let denom = FULL_DENOM.load(deps.storage)?;
+    Ok(Response::new()
+        .add_attributes(vec![attr("action", "burn")])
+        .add_submessages(build_burn_submsgs(
+            deps.storage,
+            &env,
+            &env.contract.address,
+            amount,
+        )?))
+}
+
+/// Builds a factory burn msg for tokens held by this contract, updating the
+/// total amount burned, followed by hook msgs reporting the burn of `owner`'s
+/// tokens.
+pub fn build_burn_submsgs(
+    storage: &mut dyn Storage,
+    env: &Env,
+    owner: &Addr,
+    amount: Uint128,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let factory = FACTORY.load(storage)?;
+    let denom = FULL_DENOM.load(storage)?;
 
-    AMOUNT_BURNED.update(deps.storage, |n| -> Result<_, ContractError> {
-        Ok(n.checked_add(amount.into())
-            .map_err(|e| ContractError::Std(StdError::overflow(e)))?)
+    AMOUNT_BURNED.update(storage, |n| -> Result<_, ContractError> {
+        n.checked_add(amount.into())
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))
     })?;
 
-    Ok(Response::new()
-        .add_attributes(vec![attr("action", "burn")])
-        .add_message(factory.burn(env.contract.address.to_owned(), &denom, amount)))
+    let mut msgs = vec![SubMsg::new(factory.burn(
+        env.contract.address.to_owned(),
+        &denom,
+        amount,
+    ))];
+    msgs.extend(build_burn_hook_submsgs(storage, owner, amount)?);
+
+    Ok(msgs)
 }
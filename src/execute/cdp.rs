@@ -0,0 +1,443 @@
+use crate::{
+    error::ContractError,
+    msg::{OraclePriceResponse, OracleQueryMsg},
+    state::{
+        models::{Cdp, CdpPosition, LiquidationEvent},
+        storage::{CDP, CDP_LIQUIDATIONS, CDP_POSITIONS, CDP_STATE, FULL_DENOM},
+    },
+};
+use cosmwasm_std::{
+    attr, Addr, BankMsg, Coin, CosmosMsg, Decimal256, QuerierWrapper, Response, StdError, Storage,
+    Uint128,
+};
+use cw_utils::must_pay;
+
+use super::{burn::build_burn_submsgs, format_coins, mint::build_backed_mint_submsg, Context};
+
+pub fn exec_configure_cdp(
+    ctx: Context,
+    oracle: Addr,
+    collateral_denoms: Vec<String>,
+    collateral_ratio: Decimal256,
+    liquidation_ratio: Decimal256,
+    liquidation_bonus: Decimal256,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let oracle = deps.api.addr_validate(oracle.as_str())?;
+
+    if collateral_denoms.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "at least one collateral denom is required".to_owned(),
+        });
+    }
+
+    if collateral_denoms.contains(&FULL_DENOM.load(deps.storage)?) {
+        return Err(ContractError::ValidationError {
+            reason: "cannot use the minted denom as collateral".to_owned(),
+        });
+    }
+
+    if liquidation_ratio <= Decimal256::one() || collateral_ratio < liquidation_ratio {
+        return Err(ContractError::ValidationError {
+            reason: "require collateral_ratio >= liquidation_ratio > 1".to_owned(),
+        });
+    }
+
+    // A bonus of the whole debt or more would pay liquidators more collateral
+    // than a position at the liquidation ratio can cover
+    if liquidation_bonus >= Decimal256::one() {
+        return Err(ContractError::ValidationError {
+            reason: "liquidation bonus must be less than 1".to_owned(),
+        });
+    }
+
+    // Dropping a collateral denom would leave existing positions unvalued
+    if let Some(cdp) = CDP.may_load(deps.storage)? {
+        if let Some(denom) = cdp
+            .collateral_denoms
+            .iter()
+            .find(|d| !collateral_denoms.contains(d))
+        {
+            return Err(ContractError::ValidationError {
+                reason: format!("cannot remove collateral denom {}", denom),
+            });
+        }
+    }
+
+    CDP.save(
+        deps.storage,
+        &Cdp {
+            oracle: oracle.to_owned(),
+            collateral_denoms: collateral_denoms.to_owned(),
+            collateral_ratio,
+            liquidation_ratio,
+            liquidation_bonus,
+        },
+    )?;
+
+    if CDP_STATE.may_load(deps.storage)?.is_none() {
+        CDP_STATE.save(deps.storage, &Default::default())?;
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "configure_cdp"),
+        attr("oracle", oracle.to_string()),
+        attr("collateral_denoms", collateral_denoms.join(",")),
+        attr("collateral_ratio", collateral_ratio.to_string()),
+        attr("liquidation_ratio", liquidation_ratio.to_string()),
+        attr("liquidation_bonus", liquidation_bonus.to_string()),
+    ]))
+}
+
+pub fn exec_deposit_collateral(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+    let cdp = load_cdp(deps.storage)?;
+
+    if info.funds.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "no collateral attached".to_owned(),
+        });
+    }
+
+    let mut position = CDP_POSITIONS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+
+    for coin in info.funds.iter() {
+        if !cdp.collateral_denoms.contains(&coin.denom) {
+            return Err(ContractError::ValidationError {
+                reason: format!("{} is not accepted as collateral", coin.denom),
+            });
+        }
+        position.add_collateral(coin)?;
+    }
+
+    CDP_POSITIONS.save(deps.storage, &info.sender, &position)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "deposit_collateral"),
+        attr("owner", info.sender.to_string()),
+        attr("collateral", format_coins(&info.funds)),
+    ]))
+}
+
+pub fn exec_withdraw_collateral(
+    ctx: Context,
+    collateral: Vec<Coin>,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+    let cdp = load_cdp(deps.storage)?;
+    let mut position = load_position(deps.storage, &info.sender)?;
+    let collateral: Vec<Coin> = collateral
+        .into_iter()
+        .filter(|c| !c.amount.is_zero())
+        .collect();
+
+    if collateral.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "no collateral to withdraw".to_owned(),
+        });
+    }
+
+    for coin in collateral.iter() {
+        position.remove_collateral(coin)?;
+    }
+
+    ensure_collateralized(deps.querier, &cdp, &position)?;
+    save_position(deps.storage, &info.sender, &position)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "withdraw_collateral"),
+            attr("owner", info.sender.to_string()),
+            attr("collateral", format_coins(&collateral)),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: collateral,
+        }))
+}
+
+/// Mint against the sender's collateral, up to the collateral ratio
+pub fn exec_borrow(
+    ctx: Context,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let cdp = load_cdp(deps.storage)?;
+    let mut position = load_position(deps.storage, &info.sender)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "amount must be non-zero".to_owned(),
+        });
+    }
+
+    position.debt = position
+        .debt
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    ensure_collateralized(deps.querier, &cdp, &position)?;
+    CDP_POSITIONS.save(deps.storage, &info.sender, &position)?;
+
+    CDP_STATE.update(deps.storage, |mut state| -> Result<_, ContractError> {
+        state.total_debt = state
+            .total_debt
+            .checked_add(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        Ok(state)
+    })?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "borrow"),
+            attr("owner", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+            attr("debt", position.debt.to_string()),
+        ])
+        .add_submessage(build_backed_mint_submsg(
+            deps.storage,
+            &env,
+            &info.sender,
+            amount,
+        )?))
+}
+
+/// Burn attached tokens against the sender's debt, refunding any excess
+pub fn exec_repay(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    load_cdp(deps.storage)?;
+
+    let full_denom = FULL_DENOM.load(deps.storage)?;
+    let paid = must_pay(&info, &full_denom)?;
+    let mut position = load_position(deps.storage, &info.sender)?;
+
+    if position.debt.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "position has no debt".to_owned(),
+        });
+    }
+
+    let repaid = paid.min(position.debt);
+    position.debt -= repaid;
+
+    save_position(deps.storage, &info.sender, &position)?;
+    reduce_total_debt(deps.storage, repaid)?;
+
+    let burn_msgs = build_burn_submsgs(deps.storage, &env, &info.sender, repaid)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if paid > repaid {
+        msgs.push(
+            BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![Coin::new((paid - repaid).u128(), full_denom)],
+            }
+            .into(),
+        );
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "repay"),
+            attr("owner", info.sender.to_string()),
+            attr("repaid", repaid.to_string()),
+            attr("debt", position.debt.to_string()),
+        ])
+        .add_submessages(burn_msgs)
+        .add_messages(msgs))
+}
+
+/// Repay an undercollateralized position's debt with attached tokens in
+/// exchange for its collateral, plus the liquidation bonus
+pub fn exec_liquidate(
+    ctx: Context,
+    owner: Addr,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let cdp = load_cdp(deps.storage)?;
+    let full_denom = FULL_DENOM.load(deps.storage)?;
+    let paid = must_pay(&info, &full_denom)?;
+    let mut position = load_position(deps.storage, &owner)?;
+    let value = collateral_value(deps.querier, &cdp, &position.collateral)?;
+
+    if !is_liquidatable(&cdp, &position, value) {
+        return Err(ContractError::ValidationError {
+            reason: "position is not liquidatable".to_owned(),
+        });
+    }
+
+    let repaid = paid.min(position.debt);
+    let seize_value = (Decimal256::from_ratio(repaid, 1u128)
+        * (Decimal256::one() + cdp.liquidation_bonus))
+        .to_uint_floor();
+    let seize_value = Uint128::try_from(seize_value).unwrap_or(Uint128::MAX);
+
+    let mut seized: Vec<Coin> = vec![];
+    for coin in position.collateral.iter() {
+        let amount = if value.is_zero() || seize_value >= value {
+            coin.amount
+        } else {
+            coin.amount.multiply_ratio(seize_value, value)
+        };
+        if !amount.is_zero() {
+            seized.push(Coin::new(amount.u128(), coin.denom.to_owned()));
+        }
+    }
+
+    for coin in seized.iter() {
+        position.remove_collateral(coin)?;
+    }
+    position.debt -= repaid;
+
+    save_position(deps.storage, &owner, &position)?;
+    reduce_total_debt(deps.storage, repaid)?;
+
+    let mut state = CDP_STATE.load(deps.storage)?;
+    state.liquidation_count += 1;
+    CDP_STATE.save(deps.storage, &state)?;
+
+    CDP_LIQUIDATIONS.save(
+        deps.storage,
+        state.liquidation_count,
+        &LiquidationEvent {
+            id: state.liquidation_count,
+            owner: owner.to_owned(),
+            liquidator: info.sender.to_owned(),
+            repaid,
+            collateral_seized: seized.to_owned(),
+            time: env.block.time,
+        },
+    )?;
+
+    let burn_msgs = build_burn_submsgs(deps.storage, &env, &info.sender, repaid)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !seized.is_empty() {
+        msgs.push(
+            BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: seized.to_owned(),
+            }
+            .into(),
+        );
+    }
+    if paid > repaid {
+        msgs.push(
+            BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![Coin::new((paid - repaid).u128(), full_denom)],
+            }
+            .into(),
+        );
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "liquidate"),
+            attr("owner", owner.to_string()),
+            attr("liquidator", info.sender.to_string()),
+            attr("repaid", repaid.to_string()),
+            attr("collateral_seized", format_coins(&seized)),
+        ])
+        .add_submessages(burn_msgs)
+        .add_messages(msgs))
+}
+
+pub fn load_cdp(storage: &dyn Storage) -> Result<Cdp, ContractError> {
+    CDP.may_load(storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "cdp not configured".to_owned(),
+        })
+}
+
+/// Value collateral in base units of the tokenfactory denom, using the
+/// configured oracle
+pub fn collateral_value(
+    querier: QuerierWrapper,
+    cdp: &Cdp,
+    collateral: &[Coin],
+) -> Result<Uint128, ContractError> {
+    let mut total = Decimal256::zero();
+    for coin in collateral.iter() {
+        let OraclePriceResponse { price } = querier.query_wasm_smart(
+            cdp.oracle.to_owned(),
+            &OracleQueryMsg::Price {
+                denom: coin.denom.to_owned(),
+            },
+        )?;
+        total = Decimal256::from_ratio(coin.amount, 1u128)
+            .checked_mul(price)
+            .and_then(|v| v.checked_add(total))
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    }
+    Uint128::try_from(total.to_uint_floor())
+        .map_err(|e| ContractError::Std(StdError::generic_err(e.to_string())))
+}
+
+pub fn is_liquidatable(
+    cdp: &Cdp,
+    position: &CdpPosition,
+    collateral_value: Uint128,
+) -> bool {
+    position
+        .ratio(collateral_value)
+        .map(|ratio| ratio < cdp.liquidation_ratio)
+        .unwrap_or(false)
+}
+
+fn load_position(
+    storage: &dyn Storage,
+    owner: &Addr,
+) -> Result<CdpPosition, ContractError> {
+    CDP_POSITIONS
+        .may_load(storage, owner)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: format!("no position for {}", owner),
+        })
+}
+
+fn save_position(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    position: &CdpPosition,
+) -> Result<(), ContractError> {
+    if position.is_empty() {
+        CDP_POSITIONS.remove(storage, owner);
+    } else {
+        CDP_POSITIONS.save(storage, owner, position)?;
+    }
+    Ok(())
+}
+
+fn ensure_collateralized(
+    querier: QuerierWrapper,
+    cdp: &Cdp,
+    position: &CdpPosition,
+) -> Result<(), ContractError> {
+    let value = collateral_value(querier, cdp, &position.collateral)?;
+    if let Some(ratio) = position.ratio(value) {
+        if ratio < cdp.collateral_ratio {
+            return Err(ContractError::ValidationError {
+                reason: format!(
+                    "collateral ratio {} below minimum {}",
+                    ratio, cdp.collateral_ratio
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn reduce_total_debt(
+    storage: &mut dyn Storage,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    CDP_STATE.update(storage, |mut state| -> Result<_, ContractError> {
+        state.total_debt = state
+            .total_debt
+            .checked_sub(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        Ok(state)
+    })?;
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{Curve, CurveState, CurveType},
+        storage::{CURVE, CURVE_STATE, DENOM_METADATA, FULL_DENOM, MANAGER},
+    },
+};
+use cosmwasm_std::{attr, Addr, BankMsg, Coin, Decimal256, Response, StdError, Storage, Uint128};
+use cw_utils::must_pay;
+
+use super::{burn::build_burn_submsgs, mint::build_backed_mint_submsg, Context};
+
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+pub fn exec_configure_curve(
+    ctx: Context,
+    reserve_denom: String,
+    curve_type: CurveType,
+    entry_fee_bps: Option<u16>,
+    exit_fee_bps: Option<u16>,
+    treasury: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let entry_fee_bps = entry_fee_bps.unwrap_or_default();
+    let exit_fee_bps = exit_fee_bps.unwrap_or_default();
+
+    curve_type.validate()?;
+
+    if entry_fee_bps >= MAX_FEE_BPS || exit_fee_bps >= MAX_FEE_BPS {
+        return Err(ContractError::ValidationError {
+            reason: format!("curve fees must be less than {} bps", MAX_FEE_BPS),
+        });
+    }
+
+    // Changing the curve under existing holders would break the reserve invariant
+    let state = CURVE_STATE.may_load(deps.storage)?.unwrap_or_default();
+    if !state.supply.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "cannot change curve while curve supply is outstanding".to_owned(),
+        });
+    }
+
+    let treasury = match treasury {
+        Some(addr) => deps.api.addr_validate(addr.as_str())?,
+        None => MANAGER.load(deps.storage)?,
+    };
+
+    let decimals = DENOM_METADATA.load(deps.storage)?.decimals;
+    let token_unit = Uint128::from(10u128)
+        .checked_pow(decimals)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    CURVE.save(
+        deps.storage,
+        &Curve {
+            reserve_denom: reserve_denom.to_owned(),
+            curve_type,
+            token_unit,
+            entry_fee_bps,
+            exit_fee_bps,
+            treasury: treasury.to_owned(),
+        },
+    )?;
+    CURVE_STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "configure_curve"),
+        attr("reserve_denom", reserve_denom),
+        attr("treasury", treasury.to_string()),
+    ]))
+}
+
+/// Mint as many tokens as the attached reserve buys at the current point on
+/// the curve, less the entry fee.
+pub fn exec_buy_from_curve(
+    ctx: Context,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let recipient = ctx.recipient_or_sender(recipient)?;
+    let Context { deps, env, info } = ctx;
+    let curve = load_curve(deps.storage)?;
+    let mut state = CURVE_STATE.load(deps.storage)?;
+
+    let payment = must_pay(&info, &curve.reserve_denom)?;
+    let fee = payment.multiply_ratio(curve.entry_fee_bps, MAX_FEE_BPS);
+    let reserve_in = payment - fee;
+    let amount = curve.amount_for_reserve(state.supply, reserve_in)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "payment does not buy any tokens".to_owned(),
+        });
+    }
+
+    state.supply = state
+        .supply
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.reserve = state
+        .reserve
+        .checked_add(reserve_in)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    ensure_reserve_invariant(&curve, &state)?;
+    CURVE_STATE.save(deps.storage, &state)?;
+
+    let mut resp = Response::new()
+        .add_attributes(vec![
+            attr("action", "buy_from_curve"),
+            attr("buyer", info.sender.to_string()),
+            attr("recipient", recipient.to_string()),
+            attr("amount", amount.to_string()),
+            attr("reserve_in", reserve_in.to_string()),
+            attr("fee", fee.to_string()),
+        ])
+        .add_submessage(build_backed_mint_submsg(
+            deps.storage,
+            &env,
+            &recipient,
+            amount,
+        )?);
+
+    if !fee.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: curve.treasury.to_string(),
+            amount: vec![Coin::new(fee.u128(), curve.reserve_denom.to_owned())],
+        });
+    }
+
+    Ok(resp)
+}
+
+/// Burn attached tokens, paying out the reserve they release from the curve,
+/// less the exit fee.
+pub fn exec_sell_to_curve(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let curve = load_curve(deps.storage)?;
+    let mut state = CURVE_STATE.load(deps.storage)?;
+
+    // Only tokens the curve issued are backed by its reserve. Tokens minted
+    // any other way can't be sold into it.
+    let amount = must_pay(&info, &FULL_DENOM.load(deps.storage)?)?;
+    if amount > state.supply {
+        return Err(ContractError::ValidationError {
+            reason: "amount exceeds curve-issued supply".to_owned(),
+        });
+    }
+
+    let proceeds = curve.proceeds_from_sale(state.supply, amount)?;
+    let fee = proceeds.multiply_ratio(curve.exit_fee_bps, MAX_FEE_BPS);
+    let reserve_out = proceeds - fee;
+
+    state.supply = state
+        .supply
+        .checked_sub(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.reserve = state
+        .reserve
+        .checked_sub(proceeds)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    ensure_reserve_invariant(&curve, &state)?;
+
+    let balance = deps.querier.query_balance(
+        env.contract.address.to_owned(),
+        curve.reserve_denom.to_owned(),
+    )?;
+    if balance.amount < proceeds {
+        return Err(ContractError::ValidationError {
+            reason: "insufficient reserve balance".to_owned(),
+        });
+    }
+
+    CURVE_STATE.save(deps.storage, &state)?;
+
+    let mut resp = Response::new()
+        .add_attributes(vec![
+            attr("action", "sell_to_curve"),
+            attr("seller", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+            attr("reserve_out", reserve_out.to_string()),
+            attr("fee", fee.to_string()),
+        ])
+        .add_submessages(build_burn_submsgs(
+            deps.storage,
+            &env,
+            &info.sender,
+            amount,
+        )?);
+
+    if !reserve_out.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(
+                reserve_out.u128(),
+                curve.reserve_denom.to_owned(),
+            )],
+        });
+    }
+    if !fee.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: curve.treasury.to_string(),
+            amount: vec![Coin::new(fee.u128(), curve.reserve_denom.to_owned())],
+        });
+    }
+
+    Ok(resp)
+}
+
+pub fn load_curve(storage: &dyn Storage) -> Result<Curve, ContractError> {
+    CURVE
+        .may_load(storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "no bonding curve configured".to_owned(),
+        })
+}
+
+/// The reserve held must always cover the integral of the curve's price
+fn ensure_reserve_invariant(
+    curve: &Curve,
+    state: &CurveState,
+) -> Result<(), ContractError> {
+    if Decimal256::from_ratio(state.reserve, 1u128) < curve.reserve(state.supply)? {
+        return Err(ContractError::ValidationError {
+            reason: "curve reserve invariant violated".to_owned(),
+        });
+    }
+    Ok(())
+}
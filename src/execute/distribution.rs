@@ -0,0 +1,68 @@
+use crate::{
+    error::ContractError,
+    msg::MintParams,
+    state::{
+        queue_initial_balances, send_initial_balances,
+        storage::{FACTORY, FULL_DENOM, INITIAL_BALANCES},
+        DEFAULT_LIMIT, INITIAL_BALANCES_REPLY_ID, MAX_LIMIT,
+    },
+};
+use cosmwasm_std::{attr, Response, SubMsg};
+
+use super::{mint::ensure_within_max_supply, Context};
+
+/// Send the next page of initial balances still queued from instantiation
+pub fn exec_continue_distribution(
+    ctx: Context,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+
+    if INITIAL_BALANCES.is_empty(deps.storage)? {
+        return Err(ContractError::ValidationError {
+            reason: "initial distribution is complete".to_owned(),
+        });
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let send_msgs = send_initial_balances(deps.storage, limit)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "continue_distribution"),
+            attr("count", send_msgs.len().to_string()),
+            attr("remaining", INITIAL_BALANCES.len(deps.storage)?.to_string()),
+        ])
+        .add_submessages(send_msgs))
+}
+
+/// Mint a page of initial balances, which are sent out in the reply and by
+/// `ContinueDistribution`
+pub fn exec_append_initial_balances(
+    ctx: Context,
+    balances: Vec<MintParams>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+
+    if balances.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "no initial balances given".to_owned(),
+        });
+    }
+
+    let factory = FACTORY.load(deps.storage)?;
+    let denom = FULL_DENOM.load(deps.storage)?;
+    let amount = queue_initial_balances(deps.storage, deps.api, &balances)?;
+    ensure_within_max_supply(deps.storage, amount)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "append_initial_balances"),
+            attr("count", balances.len().to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessage(SubMsg::reply_always(
+            factory.mint(env.contract.address, denom, amount),
+            INITIAL_BALANCES_REPLY_ID,
+        )))
+}
@@ -0,0 +1,204 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{EmissionSchedule, EmissionStream},
+        storage::{EMISSION_CURSOR, EMISSION_ID_COUNTER, EMISSION_STREAMS},
+        DEFAULT_LIMIT, MAX_LIMIT,
+    },
+};
+use cosmwasm_std::{
+    attr, Addr, Env, Order, Response, StdResult, Storage, SubMsg, Timestamp, Uint128,
+};
+use cw_storage_plus::Bound;
+
+use super::{
+    mint::{build_mint_submsg, remaining_supply, take_from_budget},
+    Context,
+};
+
+pub fn exec_add_emission(
+    ctx: Context,
+    recipient: Addr,
+    start: Option<Timestamp>,
+    schedule: EmissionSchedule,
+    total: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let recipient = deps.api.addr_validate(recipient.as_str())?;
+    let start = start.unwrap_or(env.block.time);
+
+    let is_valid = match &schedule {
+        EmissionSchedule::Linear { rate_per_second } => !rate_per_second.is_zero(),
+        EmissionSchedule::Halving {
+            initial_rate_per_second,
+            epoch_seconds,
+        } => !initial_rate_per_second.is_zero() && *epoch_seconds > 0,
+    };
+    if !is_valid || total.map(|t| t.is_zero()).unwrap_or(false) {
+        return Err(ContractError::ValidationError {
+            reason: "emission rate, epoch and total must be non-zero".to_owned(),
+        });
+    }
+
+    let id = EMISSION_ID_COUNTER
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    EMISSION_ID_COUNTER.save(deps.storage, &id)?;
+
+    EMISSION_STREAMS.save(
+        deps.storage,
+        id,
+        &EmissionStream {
+            id,
+            recipient: recipient.to_owned(),
+            start,
+            schedule,
+            total,
+            emitted: Uint128::zero(),
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "add_emission"),
+        attr("id", id.to_string()),
+        attr("recipient", recipient.to_string()),
+        attr("start", start.to_string()),
+    ]))
+}
+
+/// Remove a stream, minting whatever it had accrued up to now. Any accrued
+/// amount that no longer fits under the max supply is forfeited.
+pub fn exec_remove_emission(
+    ctx: Context,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let mut stream = EMISSION_STREAMS
+        .may_load(deps.storage, id)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: format!("emission {} not found", id),
+        })?;
+
+    let mut budget = remaining_supply(deps.storage)?;
+    let submsg = emit_stream(deps.storage, &env, &mut stream, &mut budget)?;
+    let forfeited = stream.pending(env.block.time)?;
+    EMISSION_STREAMS.remove(deps.storage, id);
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "remove_emission"),
+            attr("id", id.to_string()),
+            attr("forfeited", forfeited.to_string()),
+        ])
+        .add_submessages(submsg))
+}
+
+/// Mint everything accrued by up to `limit` streams since their last
+/// emission, resuming after the stream the previous call stopped at
+pub fn exec_emit(
+    ctx: Context,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    if limit == 0 {
+        return Err(ContractError::ValidationError {
+            reason: "limit must be non-zero".to_owned(),
+        });
+    }
+
+    let cursor = EMISSION_CURSOR.may_load(deps.storage)?;
+    let mut budget = remaining_supply(deps.storage)?;
+    let (submsgs, total_emitted, next_cursor) =
+        emit_streams(deps.storage, &env, cursor, limit as usize, &mut budget)?;
+
+    match next_cursor {
+        Some(id) => EMISSION_CURSOR.save(deps.storage, &id)?,
+        None => EMISSION_CURSOR.remove(deps.storage),
+    }
+
+    // A page with nothing accrued still advances the cursor
+    if submsgs.is_empty() && next_cursor.is_none() {
+        return Err(ContractError::ValidationError {
+            reason: "nothing to emit".to_owned(),
+        });
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "emit"),
+            attr("count", submsgs.len().to_string()),
+            attr("amount", total_emitted.to_string()),
+        ])
+        .add_submessages(submsgs))
+}
+
+/// Emit up to `limit` streams after `start_after`, returning the mint
+/// submsgs, the total emitted, and the last stream visited if the page was
+/// full. Emissions are cut short once `budget` is used up, leaving the rest
+/// pending.
+pub fn emit_streams(
+    storage: &mut dyn Storage,
+    env: &Env,
+    start_after: Option<u64>,
+    limit: usize,
+    budget: &mut Option<Uint128>,
+) -> Result<(Vec<SubMsg>, Uint128, Option<u64>), ContractError> {
+    let streams = EMISSION_STREAMS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| r.map(|(_, stream)| stream))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let cursor = if streams.len() == limit {
+        streams.last().map(|stream| stream.id)
+    } else {
+        None
+    };
+
+    let mut submsgs: Vec<SubMsg> = vec![];
+    let mut total_emitted = Uint128::zero();
+
+    for mut stream in streams {
+        let emitted_before = stream.emitted;
+        submsgs.extend(emit_stream(storage, env, &mut stream, budget)?);
+        total_emitted += stream.emitted - emitted_before;
+
+        if stream.is_exhausted() {
+            EMISSION_STREAMS.remove(storage, stream.id);
+        } else {
+            EMISSION_STREAMS.save(storage, stream.id, &stream)?;
+        }
+    }
+
+    Ok((submsgs, total_emitted, cursor))
+}
+
+/// Build a mint for the stream's pending amount, if any, and mark it emitted.
+/// With a max supply set, only what fits in the remaining `budget` (fee
+/// included) is emitted, and the rest stays pending.
+fn emit_stream(
+    storage: &mut dyn Storage,
+    env: &Env,
+    stream: &mut EmissionStream,
+    budget: &mut Option<Uint128>,
+) -> Result<Option<SubMsg>, ContractError> {
+    let pending = take_from_budget(storage, stream.pending(env.block.time)?, budget)?;
+    if pending.is_zero() {
+        return Ok(None);
+    }
+
+    stream.emitted += pending;
+    Ok(Some(build_mint_submsg(
+        storage,
+        env,
+        &stream.recipient,
+        pending,
+    )?))
+}
@@ -0,0 +1,208 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{Holder, HolderTracking},
+        storage::{holders, FACTORY, FULL_DENOM, HOLDER_COUNT, HOLDER_TRACKING},
+    },
+};
+use cosmwasm_std::{
+    attr, Addr, CanonicalAddr, Coin, DepsMut, Env, Response, StdError, Storage, Uint128,
+};
+use sha2::{Digest, Sha256};
+
+use super::Context;
+
+/// Make this contract the denom's BeforeSend hook. Balances held before
+/// tracking is enabled are picked up with `ReconcileHolder`.
+pub fn exec_enable_holder_tracking(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let factory = FACTORY.load(deps.storage)?;
+    let denom = FULL_DENOM.load(deps.storage)?;
+
+    let msg = factory
+        .set_before_send_hook(
+            env.contract.address.to_owned(),
+            &denom,
+            env.contract.address.to_owned(),
+        )
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: format!("{} tokenfactory does not support BeforeSend hooks", factory),
+        })?;
+
+    // Module accounts are the first 20 bytes of the sha256 of the module name
+    let module_address = deps
+        .api
+        .addr_humanize(&CanonicalAddr::from(&Sha256::digest(b"tokenfactory")[..20]))?;
+
+    HOLDER_TRACKING.save(
+        deps.storage,
+        &HolderTracking {
+            module_address: module_address.to_owned(),
+        },
+    )?;
+
+    if HOLDER_COUNT.may_load(deps.storage)?.is_none() {
+        HOLDER_COUNT.save(deps.storage, &0)?;
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "enable_holder_tracking"),
+            attr("module_address", module_address.to_string()),
+        ])
+        .add_message(msg))
+}
+
+pub fn exec_reconcile_holder(
+    ctx: Context,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let address = deps.api.addr_validate(address.as_str())?;
+    let tracking = load_holder_tracking(deps.storage)?;
+
+    if is_excluded(&env, &tracking, address.as_str()) {
+        return Err(ContractError::ValidationError {
+            reason: format!("{} is not tracked as a holder", address),
+        });
+    }
+
+    let previous = holders()
+        .may_load(deps.storage, &address)?
+        .map(|h| h.balance)
+        .unwrap_or_default();
+    let balance = deps
+        .querier
+        .query_balance(&address, FULL_DENOM.load(deps.storage)?)?
+        .amount;
+
+    save_balance(deps.storage, &address, previous, balance)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "reconcile_holder"),
+        attr("address", address.to_string()),
+        attr("previous", previous.to_string()),
+        attr("balance", balance.to_string()),
+    ]))
+}
+
+/// Mirror a transfer of the denom into holder balances. Mints and burns are
+/// seen as transfers out of and into this contract, which, like the module
+/// account, is not counted as a holder. Senders the mirror under-counts are
+/// resynced from their bank balance.
+pub fn sudo_track_before_send(
+    deps: DepsMut,
+    env: Env,
+    from: String,
+    to: String,
+    amount: Coin,
+) -> Result<Response, ContractError> {
+    let tracking = match HOLDER_TRACKING.may_load(deps.storage)? {
+        Some(tracking) => tracking,
+        None => return Ok(Response::new()),
+    };
+
+    if amount.denom != FULL_DENOM.load(deps.storage)? || amount.amount.is_zero() {
+        return Ok(Response::new());
+    }
+
+    let mut res = Response::new();
+
+    if !is_excluded(&env, &tracking, &from) {
+        let from = Addr::unchecked(from);
+        let previous = load_balance(deps.storage, &from)?;
+        let balance = match previous.checked_sub(amount.amount) {
+            Ok(balance) => balance,
+            // The mirror is missing part of this balance, such as one held
+            // before tracking was enabled. The bank has not moved the tokens
+            // yet, so resync from it.
+            Err(_) => {
+                let held = deps.querier.query_balance(&from, &amount.denom)?.amount;
+                res = res.add_attributes(vec![
+                    attr("action", "resync_holder"),
+                    attr("address", from.to_string()),
+                    attr("previous", previous.to_string()),
+                    attr("held", held.to_string()),
+                ]);
+                held.checked_sub(amount.amount)
+                    .map_err(|e| ContractError::Std(StdError::overflow(e)))?
+            },
+        };
+        save_balance(deps.storage, &from, previous, balance)?;
+    }
+
+    if !is_excluded(&env, &tracking, &to) {
+        let to = Addr::unchecked(to);
+        let previous = load_balance(deps.storage, &to)?;
+        save_balance(
+            deps.storage,
+            &to,
+            previous,
+            previous
+                .checked_add(amount.amount)
+                .map_err(|e| ContractError::Std(StdError::overflow(e)))?,
+        )?;
+    }
+
+    Ok(res)
+}
+
+fn load_holder_tracking(storage: &dyn Storage) -> Result<HolderTracking, ContractError> {
+    HOLDER_TRACKING
+        .may_load(storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "holder tracking is not enabled".to_owned(),
+        })
+}
+
+fn is_excluded(
+    env: &Env,
+    tracking: &HolderTracking,
+    address: &str,
+) -> bool {
+    address == env.contract.address.as_str() || address == tracking.module_address.as_str()
+}
+
+fn load_balance(
+    storage: &dyn Storage,
+    address: &Addr,
+) -> Result<Uint128, ContractError> {
+    Ok(holders()
+        .may_load(storage, address)?
+        .map(|h| h.balance)
+        .unwrap_or_default())
+}
+
+/// Save a holder's mirrored balance, removing zero balances and keeping the
+/// holder count in step
+fn save_balance(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    previous: Uint128,
+    balance: Uint128,
+) -> Result<(), ContractError> {
+    if balance.is_zero() {
+        holders().remove(storage, address)?;
+    } else {
+        holders().save(
+            storage,
+            address,
+            &Holder {
+                address: address.to_owned(),
+                balance,
+            },
+        )?;
+    }
+
+    let count = HOLDER_COUNT.may_load(storage)?.unwrap_or_default();
+    if previous.is_zero() && !balance.is_zero() {
+        HOLDER_COUNT.save(storage, &(count + 1))?;
+    } else if !previous.is_zero() && balance.is_zero() {
+        let count = count.checked_sub(1).ok_or_else(|| {
+            StdError::generic_err("holder count is out of step with holder balances")
+        })?;
+        HOLDER_COUNT.save(storage, &count)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use crate::{
+    error::ContractError,
+    msg::TokenFactoryHookMsg,
+    state::{
+        models::Hook,
+        storage::{FULL_DENOM, HOOKS},
+        HOOK_GAS_LIMIT, HOOK_REPLY_ID, MAX_HOOKS,
+    },
+};
+use cosmwasm_std::{
+    attr, to_json_binary, Addr, DepsMut, Order, Reply, Response, StdResult, Storage, SubMsg,
+    SubMsgResult, Uint128, WasmMsg,
+};
+
+use super::Context;
+
+pub fn exec_add_hook(
+    ctx: Context,
+    contract: Addr,
+    is_blocking: Option<bool>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let contract = deps.api.addr_validate(contract.as_str())?;
+    let is_blocking = is_blocking.unwrap_or(false);
+
+    if !HOOKS.has(deps.storage, &contract) && hook_count(deps.storage) >= MAX_HOOKS {
+        return Err(ContractError::ValidationError {
+            reason: format!("cannot add more than {} hooks", MAX_HOOKS),
+        });
+    }
+
+    HOOKS.save(
+        deps.storage,
+        &contract,
+        &Hook {
+            contract: contract.to_owned(),
+            is_blocking,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "add_hook"),
+        attr("contract", contract.to_string()),
+        attr("is_blocking", is_blocking.to_string()),
+    ]))
+}
+
+pub fn exec_remove_hook(
+    ctx: Context,
+    contract: Addr,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+
+    if !HOOKS.has(deps.storage, &contract) {
+        return Err(ContractError::ValidationError {
+            reason: format!("{} is not a hook", contract),
+        });
+    }
+
+    HOOKS.remove(deps.storage, &contract);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "remove_hook"),
+        attr("contract", contract.to_string()),
+    ]))
+}
+
+/// Number of registered hooks, each sent a msg on every mint and burn
+pub fn hook_count(storage: &dyn Storage) -> usize {
+    HOOKS.keys(storage, None, None, Order::Ascending).count()
+}
+
+/// Hook msgs to send after minting `amount` to `recipient`
+pub fn build_mint_hook_submsgs(
+    storage: &dyn Storage,
+    recipient: &str,
+    amount: Uint128,
+) -> Result<Vec<SubMsg>, ContractError> {
+    build_hook_submsgs(storage, |denom| TokenFactoryHookMsg::Minted {
+        recipient: recipient.to_owned(),
+        amount,
+        denom,
+    })
+}
+
+/// Hook msgs to send after burning `amount` held by `owner`
+pub fn build_burn_hook_submsgs(
+    storage: &dyn Storage,
+    owner: &Addr,
+    amount: Uint128,
+) -> Result<Vec<SubMsg>, ContractError> {
+    build_hook_submsgs(storage, |denom| TokenFactoryHookMsg::Burned {
+        owner: owner.to_string(),
+        amount,
+        denom,
+    })
+}
+
+fn build_hook_submsgs(
+    storage: &dyn Storage,
+    build_msg: impl FnOnce(String) -> TokenFactoryHookMsg,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let hooks = HOOKS
+        .range(storage, None, None, Order::Ascending)
+        .map(|r| r.map(|(_, hook)| hook))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    if hooks.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let msg = to_json_binary(&build_msg(FULL_DENOM.load(storage)?))?;
+
+    Ok(hooks
+        .into_iter()
+        .map(|hook| {
+            let execute = WasmMsg::Execute {
+                contract_addr: hook.contract.to_string(),
+                msg: msg.to_owned(),
+                funds: vec![],
+            };
+            if hook.is_blocking {
+                SubMsg::new(execute)
+            } else {
+                SubMsg::reply_on_error(execute, HOOK_REPLY_ID).with_gas_limit(HOOK_GAS_LIMIT)
+            }
+        })
+        .collect())
+}
+
+/// Swallow a failed non-blocking hook, whose state changes were rolled back
+pub fn handle_hook_reply(
+    _deps: DepsMut,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    match reply.result {
+        SubMsgResult::Err(e) => Ok(Response::new()
+            .add_attribute("action", "hook_failed")
+            .add_attribute("error", e)),
+        SubMsgResult::Ok(_) => Ok(Response::new()),
+    }
+}
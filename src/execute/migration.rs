@@ -0,0 +1,414 @@
+use crate::{
+    error::ContractError,
+    msg::MintParams,
+    state::{
+        models::{Cw20Migration, DecimalConversion, MigrationProgress},
+        storage::{
+            CW20_MIGRATION, DENOM_METADATA, MIGRATION_CLAIMED, MIGRATION_PROGRESS,
+            MIGRATION_UNCLAIMED,
+        },
+        DEFAULT_LIMIT, MAX_LIMIT,
+    },
+};
+use cosmwasm_std::{
+    attr, to_json_binary, Addr, CosmosMsg, Env, Order, Response, StdError, StdResult, Storage,
+    SubMsg, Uint128, WasmMsg,
+};
+use cw20::{
+    AllAccountsResponse, AllowanceResponse, BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg,
+    TokenInfoResponse,
+};
+use cw_storage_plus::Bound;
+
+use super::{mint::build_backed_mint_submsg, Context};
+
+pub fn exec_start_cw20_migration(
+    ctx: Context,
+    token: Addr,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let token = deps.api.addr_validate(token.as_str())?;
+
+    if CW20_MIGRATION.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::ValidationError {
+            reason: "cw20 migration already started".to_owned(),
+        });
+    }
+
+    let token_info: TokenInfoResponse = deps
+        .querier
+        .query_wasm_smart(token.to_owned(), &Cw20QueryMsg::TokenInfo {})?;
+
+    let native_decimals = DENOM_METADATA.load(deps.storage)?.decimals;
+
+    CW20_MIGRATION.save(
+        deps.storage,
+        &Cw20Migration {
+            token: token.to_owned(),
+            conversion: DecimalConversion {
+                cw20_decimals: token_info.decimals as u32,
+                native_decimals,
+            },
+            snapshot_cursor: None,
+            snapshot_complete: false,
+            finalized: false,
+            push_cursor: None,
+        },
+    )?;
+    MIGRATION_PROGRESS.save(deps.storage, &MigrationProgress::default())?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "start_cw20_migration"),
+        attr("cw20_address", token.to_string()),
+    ]))
+}
+
+/// Record legacy cw20 balances, given in cw20 units, that were snapshotted
+/// off-chain. Re-uploading an address replaces its previous balance.
+pub fn exec_upload_migration_snapshot(
+    ctx: Context,
+    balances: Vec<MintParams>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let migration = load_unfinalized_migration(deps.storage)?;
+    let mut progress = MIGRATION_PROGRESS.load(deps.storage)?;
+
+    for MintParams { address, amount } in balances.iter() {
+        let address = deps.api.addr_validate(address.as_str())?;
+        let amount = migration.conversion.to_native_floor(*amount)?;
+        record_balance(deps.storage, &address, amount, &mut progress)?;
+    }
+
+    MIGRATION_PROGRESS.save(deps.storage, &progress)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "upload_migration_snapshot"),
+        attr("count", balances.len().to_string()),
+    ]))
+}
+
+/// Read the next page of holders directly from the legacy cw20 token
+pub fn exec_snapshot_cw20_balances(
+    ctx: Context,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let mut migration = load_unfinalized_migration(deps.storage)?;
+    let mut progress = MIGRATION_PROGRESS.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+    if migration.snapshot_complete {
+        return Err(ContractError::ValidationError {
+            reason: "cw20 snapshot already complete".to_owned(),
+        });
+    }
+
+    let AllAccountsResponse { accounts } = deps.querier.query_wasm_smart(
+        migration.token.to_owned(),
+        &Cw20QueryMsg::AllAccounts {
+            start_after: migration.snapshot_cursor.to_owned(),
+            limit: Some(limit),
+        },
+    )?;
+
+    for account in accounts.iter() {
+        let BalanceResponse { balance } = deps.querier.query_wasm_smart(
+            migration.token.to_owned(),
+            &Cw20QueryMsg::Balance {
+                address: account.to_owned(),
+            },
+        )?;
+        let address = deps.api.addr_validate(account)?;
+        let amount = migration.conversion.to_native_floor(balance)?;
+        record_balance(deps.storage, &address, amount, &mut progress)?;
+    }
+
+    migration.snapshot_complete = (accounts.len() as u32) < limit;
+    if let Some(last) = accounts.last() {
+        migration.snapshot_cursor = Some(last.to_owned());
+    }
+
+    CW20_MIGRATION.save(deps.storage, &migration)?;
+    MIGRATION_PROGRESS.save(deps.storage, &progress)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "snapshot_cw20_balances"),
+        attr("count", accounts.len().to_string()),
+        attr("complete", migration.snapshot_complete.to_string()),
+    ]))
+}
+
+/// Freeze the snapshot, allowing holders to claim
+pub fn exec_finalize_cw20_migration(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let mut migration = load_unfinalized_migration(deps.storage)?;
+    migration.finalized = true;
+    CW20_MIGRATION.save(deps.storage, &migration)?;
+    Ok(Response::new().add_attributes(vec![attr("action", "finalize_cw20_migration")]))
+}
+
+pub fn exec_claim_migration(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let migration = load_finalized_migration(deps.storage)?;
+
+    let amount = MIGRATION_UNCLAIMED
+        .may_load(deps.storage, &info.sender)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "nothing to claim".to_owned(),
+        })?;
+    let cw20_amount = migration.conversion.to_cw20(amount)?;
+
+    let mut progress = MIGRATION_PROGRESS.load(deps.storage)?;
+    let submsg = deliver(deps.storage, &env, &info.sender, amount, &mut progress)?;
+    MIGRATION_PROGRESS.save(deps.storage, &progress)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "claim_migration"),
+            attr("recipient", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+            attr("legacy_amount_burned", cw20_amount.to_string()),
+        ])
+        .add_message(build_burn_from_msg(&migration, &info.sender, cw20_amount)?)
+        .add_submessage(submsg))
+}
+
+/// Claim for the next page of holders, burning their legacy cw20 through the
+/// allowance each has granted this contract. Holders whose allowance or
+/// balance doesn't cover their snapshot balance are skipped.
+pub fn exec_push_migration(
+    ctx: Context,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let mut migration = load_finalized_migration(deps.storage)?;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let page = MIGRATION_UNCLAIMED
+        .range(
+            deps.storage,
+            migration.push_cursor.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut progress = MIGRATION_PROGRESS.load(deps.storage)?;
+    let mut msgs: Vec<CosmosMsg> = Vec::with_capacity(page.len());
+    let mut submsgs: Vec<SubMsg> = Vec::with_capacity(page.len());
+    let mut skipped: usize = 0;
+
+    for (address, amount) in page.iter() {
+        let cw20_amount = migration.conversion.to_cw20(*amount)?;
+        let AllowanceResponse { allowance, .. } = deps.querier.query_wasm_smart(
+            migration.token.to_owned(),
+            &Cw20QueryMsg::Allowance {
+                owner: address.to_string(),
+                spender: env.contract.address.to_string(),
+            },
+        )?;
+        let BalanceResponse { balance } = deps.querier.query_wasm_smart(
+            migration.token.to_owned(),
+            &Cw20QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )?;
+
+        if allowance < cw20_amount || balance < cw20_amount {
+            skipped += 1;
+            continue;
+        }
+
+        msgs.push(build_burn_from_msg(&migration, address, cw20_amount)?);
+        submsgs.push(deliver(
+            deps.storage,
+            &env,
+            address,
+            *amount,
+            &mut progress,
+        )?);
+    }
+
+    // Start over from the first remaining holder once the end is reached
+    migration.push_cursor = if page.len() < limit {
+        None
+    } else {
+        page.last().map(|(address, _)| address.to_owned())
+    };
+
+    CW20_MIGRATION.save(deps.storage, &migration)?;
+    MIGRATION_PROGRESS.save(deps.storage, &progress)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "push_migration"),
+            attr("count", submsgs.len().to_string()),
+            attr("skipped", skipped.to_string()),
+        ])
+        .add_messages(msgs)
+        .add_submessages(submsgs))
+}
+
+/// Burn legacy cw20 tokens sent to this contract by `owner`, claiming the
+/// equivalent out of the owner's unclaimed snapshot balance. Only accepted
+/// once the snapshot is finalized, and never beyond the unclaimed balance.
+pub fn burn_legacy_tokens(
+    ctx: Context,
+    owner: Addr,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let migration = load_migration(deps.storage)?;
+    let mut progress = MIGRATION_PROGRESS.load(deps.storage)?;
+
+    if !migration.finalized {
+        return Err(ContractError::ValidationError {
+            reason: "migration snapshot is not finalized".to_owned(),
+        });
+    }
+
+    let unclaimed = MIGRATION_UNCLAIMED
+        .may_load(deps.storage, &owner)?
+        .unwrap_or_default();
+    let claimed = migration.conversion.to_native(amount)?;
+
+    if claimed.is_zero() || claimed > unclaimed {
+        return Err(ContractError::ValidationError {
+            reason: format!(
+                "amount must be non-zero and within the unclaimed snapshot balance of {}",
+                unclaimed
+            ),
+        });
+    }
+
+    progress.legacy_amount_burned = progress
+        .legacy_amount_burned
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    let resp = Response::new()
+        .add_attributes(vec![
+            attr("action", "burn_legacy_tokens"),
+            attr("owner", owner.to_string()),
+            attr("amount", amount.to_string()),
+            attr("claimed", claimed.to_string()),
+        ])
+        .add_message(WasmMsg::Execute {
+            contract_addr: info.sender.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount })?,
+            funds: vec![],
+        })
+        .add_submessage(deliver(deps.storage, &env, &owner, claimed, &mut progress)?);
+
+    MIGRATION_PROGRESS.save(deps.storage, &progress)?;
+
+    Ok(resp)
+}
+
+fn build_burn_from_msg(
+    migration: &Cw20Migration,
+    owner: &Addr,
+    amount: Uint128,
+) -> Result<CosmosMsg, ContractError> {
+    Ok(WasmMsg::Execute {
+        contract_addr: migration.token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::BurnFrom {
+            owner: owner.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    }
+    .into())
+}
+
+fn load_unfinalized_migration(storage: &dyn Storage) -> Result<Cw20Migration, ContractError> {
+    let migration = load_migration(storage)?;
+    if migration.finalized {
+        return Err(ContractError::ValidationError {
+            reason: "cw20 migration snapshot is finalized".to_owned(),
+        });
+    }
+    Ok(migration)
+}
+
+fn load_finalized_migration(storage: &dyn Storage) -> Result<Cw20Migration, ContractError> {
+    let migration = load_migration(storage)?;
+    if !migration.finalized {
+        return Err(ContractError::ValidationError {
+            reason: "cw20 migration snapshot is not finalized".to_owned(),
+        });
+    }
+    Ok(migration)
+}
+
+fn load_migration(storage: &dyn Storage) -> Result<Cw20Migration, ContractError> {
+    CW20_MIGRATION
+        .may_load(storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "cw20 migration not started".to_owned(),
+        })
+}
+
+fn record_balance(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    amount: Uint128,
+    progress: &mut MigrationProgress,
+) -> Result<(), ContractError> {
+    if let Some(prev_amount) = MIGRATION_UNCLAIMED.may_load(storage, address)? {
+        progress.total_amount = progress
+            .total_amount
+            .checked_sub(prev_amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        progress.holder_count = progress
+            .holder_count
+            .checked_sub(1)
+            .ok_or_else(|| ContractError::Std(StdError::generic_err("holder count underflow")))?;
+        MIGRATION_UNCLAIMED.remove(storage, address);
+    }
+    if !amount.is_zero() {
+        progress.total_amount = progress
+            .total_amount
+            .checked_add(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        progress.holder_count += 1;
+        MIGRATION_UNCLAIMED.save(storage, address, &amount)?;
+    }
+    Ok(())
+}
+
+/// Pay `amount` of `address`'s unclaimed snapshot balance
+fn deliver(
+    storage: &mut dyn Storage,
+    env: &Env,
+    address: &Addr,
+    amount: Uint128,
+    progress: &mut MigrationProgress,
+) -> Result<SubMsg, ContractError> {
+    let remaining = MIGRATION_UNCLAIMED
+        .load(storage, address)?
+        .checked_sub(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    if remaining.is_zero() {
+        MIGRATION_UNCLAIMED.remove(storage, address);
+        progress.claimed_holder_count += 1;
+    } else {
+        MIGRATION_UNCLAIMED.save(storage, address, &remaining)?;
+    }
+
+    MIGRATION_CLAIMED.update(storage, address, |claimed| -> Result<_, ContractError> {
+        claimed
+            .unwrap_or_default()
+            .checked_add(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))
+    })?;
+
+    progress.claimed_amount = progress
+        .claimed_amount
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    build_backed_mint_submsg(storage, env, address, amount)
+}
@@ -1,14 +1,19 @@
 use crate::{
     error::ContractError,
-    msg::MintParams,
-    state::storage::{AMOUNT_MINTED, FACTORY, FULL_DENOM, MINT_PARAMS, MINT_REPLY_ID_COUNTER},
+    state::{
+        models::{FeePayment, MintDelivery, PendingMint, RemoteTransfer},
+        storage::{
+            AMOUNT_BURNED, AMOUNT_FEES_MINTED, AMOUNT_MINTED, FACTORY, FULL_DENOM, MAX_SUPPLY,
+            MINT_FEE, MINT_PARAMS, MINT_REPLY_ID_COUNTER,
+        },
+    },
 };
 use cosmwasm_std::{
-    attr, Addr, BankMsg, Coin, DepsMut, Reply, Response, StdError, SubMsg, SubMsgResult, Uint128,
-    Uint64,
+    attr, Addr, Attribute, BankMsg, Binary, Coin, DepsMut, Env, Reply, Response, StdError, Storage,
+    SubMsg, SubMsgResult, Uint128, Uint256, Uint64, WasmMsg,
 };
 
-use super::Context;
+use super::{hooks::build_mint_hook_submsgs, remote_mint::build_remote_transfer_submsg, Context};
 
 pub fn exec_mint(
     ctx: Context,
@@ -16,59 +21,303 @@ pub fn exec_mint(
     amount: Uint128,
 ) -> Result<Response, ContractError> {
     let Context { deps, env, .. } = ctx;
-    let denom = FULL_DENOM.load(deps.storage)?;
-    let factory = FACTORY.load(deps.storage)?;
+    Ok(Response::new()
+        .add_attributes(vec![attr("action", "mint")])
+        .add_submessage(build_mint_submsg(deps.storage, &env, &recipient, amount)?))
+}
+
+/// Builds a factory mint submsg, saving the params needed by its reply handler
+/// to forward the minted coins on to the recipient. The mint fee, if any, is
+/// minted on top of `amount`.
+pub fn build_mint_submsg(
+    storage: &mut dyn Storage,
+    env: &Env,
+    recipient: &Addr,
+    amount: Uint128,
+) -> Result<SubMsg, ContractError> {
+    let fee = mint_fee_for(storage, amount)?;
+    build_pending_mint_submsg(
+        storage,
+        env,
+        PendingMint {
+            amount,
+            fee,
+            delivery: MintDelivery::Send {
+                recipient: recipient.to_owned(),
+            },
+        },
+    )
+}
+
+/// Mint to a contract and execute it with `msg`, attaching the minted coins
+pub fn exec_mint_and_call(
+    ctx: Context,
+    contract: Addr,
+    amount: Uint128,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let contract = deps.api.addr_validate(contract.as_str())?;
+    let fee = mint_fee_for(deps.storage, amount)?;
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "mint_and_call"),
+            attr("contract", contract.to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessage(build_pending_mint_submsg(
+            deps.storage,
+            &env,
+            PendingMint {
+                amount,
+                fee,
+                delivery: MintDelivery::Execute { contract, msg },
+            },
+        )?))
+}
+
+/// Like `build_mint_submsg`, but exempt from the mint fee. Used where minted
+/// supply must stay matched one-to-one with deposits or debt.
+pub fn build_backed_mint_submsg(
+    storage: &mut dyn Storage,
+    env: &Env,
+    recipient: &Addr,
+    amount: Uint128,
+) -> Result<SubMsg, ContractError> {
+    build_pending_mint_submsg(
+        storage,
+        env,
+        PendingMint {
+            amount,
+            fee: None,
+            delivery: MintDelivery::Send {
+                recipient: recipient.to_owned(),
+            },
+        },
+    )
+}
+
+pub fn mint_fee_for(
+    storage: &dyn Storage,
+    amount: Uint128,
+) -> Result<Option<FeePayment>, ContractError> {
+    Ok(MINT_FEE
+        .may_load(storage)?
+        .and_then(|mint_fee| mint_fee.fee_for(amount)))
+}
+
+/// Amount minted through this contract less the amount burned through it
+pub fn net_supply(storage: &dyn Storage) -> Result<Uint256, ContractError> {
+    let amount_minted = AMOUNT_MINTED.may_load(storage)?.unwrap_or_default();
+    let amount_burned = AMOUNT_BURNED.may_load(storage)?.unwrap_or_default();
+    Ok(amount_minted.saturating_sub(amount_burned))
+}
+
+/// Amount that can still be minted under the max supply, if one is set
+pub fn remaining_supply(storage: &dyn Storage) -> Result<Option<Uint128>, ContractError> {
+    match MAX_SUPPLY.may_load(storage)? {
+        Some(max_supply) => {
+            let remaining = Uint256::from(max_supply).saturating_sub(net_supply(storage)?);
+            Ok(Some(Uint128::try_from(remaining).unwrap_or(Uint128::MAX)))
+        },
+        None => Ok(None),
+    }
+}
+
+/// Largest amount that can be minted out of `budget` once the mint fee is
+/// added on top
+pub fn max_mint_amount_within(
+    storage: &dyn Storage,
+    budget: Uint128,
+) -> Result<Uint128, ContractError> {
+    let bps = MINT_FEE
+        .may_load(storage)?
+        .map(|mint_fee| mint_fee.bps)
+        .unwrap_or_default();
+    let with_fee = |amount: Uint128| amount.checked_add(amount.multiply_ratio(bps, 10_000u128));
+
+    // The fee rounds down, so the proportional estimate may be short by one
+    let mut amount = budget.multiply_ratio(10_000u128, 10_000u128 + bps as u128);
+    while amount < budget && with_fee(amount + Uint128::one()).is_ok_and(|t| t <= budget) {
+        amount += Uint128::one();
+    }
+    Ok(amount)
+}
+
+/// Clamp `amount` to what fits in `budget` with its fee, deducting both.
+/// Used by batches of mints built in one transaction, where `budget` starts
+/// at the remaining supply.
+pub fn take_from_budget(
+    storage: &dyn Storage,
+    amount: Uint128,
+    budget: &mut Option<Uint128>,
+) -> Result<Uint128, ContractError> {
+    match budget {
+        Some(remaining) => {
+            let amount = amount.min(max_mint_amount_within(storage, *remaining)?);
+            let fee = mint_fee_for(storage, amount)?
+                .map(|fee| fee.amount)
+                .unwrap_or_default();
+            *remaining -= amount + fee;
+            Ok(amount)
+        },
+        None => Ok(amount),
+    }
+}
+
+pub fn ensure_within_max_supply(
+    storage: &dyn Storage,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(remaining) = remaining_supply(storage)? {
+        if amount > remaining {
+            return Err(ContractError::ValidationError {
+                reason: format!(
+                    "mint of {} exceeds the remaining supply of {}",
+                    amount, remaining
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn build_pending_mint_submsg(
+    storage: &mut dyn Storage,
+    env: &Env,
+    pending: PendingMint,
+) -> Result<SubMsg, ContractError> {
+    let denom = FULL_DENOM.load(storage)?;
+    let factory = FACTORY.load(storage)?;
+    let total = pending.total()?;
+
+    ensure_within_max_supply(storage, total)?;
 
     let reply_id = MINT_REPLY_ID_COUNTER
-        .update(deps.storage, |n| -> Result<_, ContractError> {
+        .update(storage, |n| -> Result<_, ContractError> {
             Ok(n + Uint64::one())
         })?
         .u64()
         - 1;
 
-    MINT_PARAMS.save(
-        deps.storage,
-        reply_id,
-        &MintParams {
-            address: recipient.to_owned(),
-            amount,
-        },
-    )?;
+    MINT_PARAMS.save(storage, reply_id, &pending)?;
 
-    Ok(Response::new()
-        .add_attributes(vec![attr("action", "mint")])
-        .add_submessage(SubMsg::reply_always(
-            factory.mint(env.contract.address.to_owned(), denom, amount),
-            reply_id,
-        )))
+    Ok(SubMsg::reply_always(
+        factory.mint(env.contract.address.to_owned(), denom, total),
+        reply_id,
+    ))
 }
 
 pub fn transfer_minted_coins(
     deps: DepsMut,
+    env: Env,
     reply: Reply,
 ) -> Result<Response, ContractError> {
-    let mut send_msgs: Vec<SubMsg> = Vec::with_capacity(1);
+    let mut send_msgs: Vec<SubMsg> = Vec::with_capacity(2);
+    let mut attrs: Vec<Attribute> = vec![];
     match reply.result {
         SubMsgResult::Ok(_) => {
             let denom = FULL_DENOM.load(deps.storage)?;
-            let MintParams { amount, address } = MINT_PARAMS.load(deps.storage, reply.id)?;
+            let pending = MINT_PARAMS.load(deps.storage, reply.id)?;
+            let total = pending.total()?;
 
             MINT_PARAMS.remove(deps.storage, reply.id);
 
+            // Mints built in the same transaction are each checked against
+            // the supply minted before any of them landed, so check again
+            ensure_within_max_supply(deps.storage, total)?;
+
             AMOUNT_MINTED.update(deps.storage, |n| -> Result<_, ContractError> {
-                Ok(n.checked_add(amount.into())
-                    .map_err(|e| ContractError::Std(StdError::overflow(e)))?)
+                n.checked_add(total.into())
+                    .map_err(|e| ContractError::Std(StdError::overflow(e)))
             })?;
 
-            send_msgs.push(SubMsg::new(BankMsg::Send {
-                to_address: address.to_string(),
-                amount: vec![Coin::new(amount.into(), denom.to_owned())],
-            }))
+            let coins = vec![Coin::new(pending.amount.into(), denom.to_owned())];
+
+            let hook_msgs = build_mint_hook_submsgs(
+                deps.storage,
+                &pending.delivery.recipient(),
+                pending.amount,
+            )?;
+
+            // A failed delivery fails this reply, rolling back the mint with it
+            match pending.delivery {
+                MintDelivery::Send { recipient } => {
+                    send_msgs.push(SubMsg::new(BankMsg::Send {
+                        to_address: recipient.to_string(),
+                        amount: coins,
+                    }));
+                },
+                MintDelivery::Execute { contract, msg } => {
+                    attrs.push(attr("call_contract", contract.to_string()));
+                    send_msgs.push(SubMsg::new(WasmMsg::Execute {
+                        contract_addr: contract.to_string(),
+                        msg,
+                        funds: coins,
+                    }));
+                },
+                MintDelivery::IbcTransfer {
+                    channel_id,
+                    remote_recipient,
+                    timeout_seconds,
+                    memo,
+                    refund_address,
+                } => {
+                    attrs.push(attr("channel_id", channel_id.to_owned()));
+                    attrs.push(attr("remote_recipient", remote_recipient.to_owned()));
+                    send_msgs.push(build_remote_transfer_submsg(
+                        deps.storage,
+                        &env,
+                        &denom,
+                        RemoteTransfer {
+                            channel_id,
+                            sequence: 0,
+                            remote_recipient,
+                            amount: pending.amount,
+                            refund_address,
+                            sent_at: env.block.time,
+                        },
+                        timeout_seconds,
+                        memo,
+                    )?);
+                },
+            }
+
+            send_msgs.extend(hook_msgs);
+
+            if let Some(fee) = pending.fee {
+                let fee_hook_msgs =
+                    build_mint_hook_submsgs(deps.storage, fee.recipient.as_str(), fee.amount)?;
+                send_msgs.push(build_fee_send_msg(deps.storage, &denom, fee)?);
+                send_msgs.extend(fee_hook_msgs);
+            }
         },
         SubMsgResult::Err(e) => {
             return Err(ContractError::Std(StdError::generic_err(e.to_string())))
         },
     }
 
-    Ok(Response::new().add_submessages(send_msgs))
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_submessages(send_msgs))
+}
+
+/// Record a minted fee in the contract stats and send it to its recipient
+pub fn build_fee_send_msg(
+    storage: &mut dyn Storage,
+    denom: &str,
+    fee: FeePayment,
+) -> Result<SubMsg, ContractError> {
+    let amount_fees_minted = AMOUNT_FEES_MINTED
+        .may_load(storage)?
+        .unwrap_or_default()
+        .checked_add(fee.amount.into())
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    AMOUNT_FEES_MINTED.save(storage, &amount_fees_minted)?;
+
+    Ok(SubMsg::new(BankMsg::Send {
+        to_address: fee.recipient.to_string(),
+        amount: vec![Coin::new(fee.amount.into(), denom)],
+    }))
 }
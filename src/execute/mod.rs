@@ -1,14 +1,63 @@
+pub mod airdrop;
+pub mod auction;
+pub mod automation;
+pub mod basket;
+pub mod bridge;
 pub mod burn;
+pub mod cdp;
+pub mod curve;
+pub mod distribution;
+pub mod emission;
+pub mod holders;
+pub mod hooks;
+pub mod migration;
 pub mod mint;
+pub mod payment_stream;
+pub mod raise;
+pub mod receive;
+pub mod remote_mint;
 pub mod remove_denom_admin;
+pub mod sale;
+pub mod scheduled_mint;
+pub mod set_cw20_wrapper;
 pub mod set_denom_admin;
 pub mod set_denom_metadata;
 pub mod set_manager;
+pub mod set_max_supply;
+pub mod set_mint_fee;
+pub mod staking;
+pub mod unwrap;
+pub mod voucher;
+pub mod wrapped_asset;
 
-use cosmwasm_std::{DepsMut, Env, MessageInfo};
+use cosmwasm_std::{Addr, Coin, DepsMut, Env, MessageInfo, StdResult};
 
 pub struct Context<'a> {
     pub deps: DepsMut<'a>,
     pub env: Env,
     pub info: MessageInfo,
 }
+
+impl Context<'_> {
+    /// The account to credit for a payment: `recipient` if given, else the
+    /// sender. Lets contracts and ibc-hooks intermediaries, whose derived
+    /// sender address is not controlled by anyone, act for a user.
+    pub fn recipient_or_sender(
+        &self,
+        recipient: Option<Addr>,
+    ) -> StdResult<Addr> {
+        match recipient {
+            Some(addr) => self.deps.api.addr_validate(addr.as_str()),
+            None => Ok(self.info.sender.to_owned()),
+        }
+    }
+}
+
+/// Comma-separated coins, for response attributes and error messages
+pub fn format_coins(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
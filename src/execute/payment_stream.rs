@@ -0,0 +1,147 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::PaymentStream,
+        storage::{payment_streams, PAYMENT_STREAM_ID_COUNTER},
+    },
+};
+use cosmwasm_std::{attr, Addr, Response, Storage, Timestamp, Uint128};
+
+use super::{mint::build_mint_submsg, Context};
+
+pub fn exec_create_stream(
+    ctx: Context,
+    recipient: Addr,
+    rate_per_second: Uint128,
+    start: Timestamp,
+    end: Timestamp,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let recipient = deps.api.addr_validate(recipient.as_str())?;
+
+    if rate_per_second.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "rate must be non-zero".to_owned(),
+        });
+    }
+
+    if end <= start {
+        return Err(ContractError::ValidationError {
+            reason: "stream must end after it starts".to_owned(),
+        });
+    }
+
+    let id = PAYMENT_STREAM_ID_COUNTER
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    PAYMENT_STREAM_ID_COUNTER.save(deps.storage, &id)?;
+
+    let stream = PaymentStream {
+        id,
+        recipient: recipient.to_owned(),
+        rate_per_second,
+        start,
+        end,
+        withdrawn: Uint128::zero(),
+    };
+
+    // Reject streams whose total would overflow up front
+    stream.accrued(end)?;
+
+    payment_streams().save(deps.storage, id, &stream)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "create_stream"),
+        attr("id", id.to_string()),
+        attr("recipient", recipient.to_string()),
+        attr("rate_per_second", rate_per_second.to_string()),
+        attr("start", start.to_string()),
+        attr("end", end.to_string()),
+    ]))
+}
+
+/// Mint everything accrued by the sender's stream so far
+pub fn exec_withdraw_stream(
+    ctx: Context,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let mut stream = load_stream(deps.storage, id)?;
+
+    if stream.recipient != info.sender {
+        return Err(ContractError::NotAuthorized {
+            reason: "only the stream recipient can withdraw".to_owned(),
+        });
+    }
+
+    let amount = stream.withdrawable(env.block.time)?;
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "nothing to withdraw".to_owned(),
+        });
+    }
+
+    stream.withdrawn += amount;
+
+    if env.block.time >= stream.end {
+        payment_streams().remove(deps.storage, id)?;
+    } else {
+        payment_streams().save(deps.storage, id, &stream)?;
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "withdraw_stream"),
+            attr("id", id.to_string()),
+            attr("recipient", stream.recipient.to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessage(build_mint_submsg(
+            deps.storage,
+            &env,
+            &stream.recipient,
+            amount,
+        )?))
+}
+
+/// Stop a stream, minting whatever it had accrued to the recipient
+pub fn exec_cancel_stream(
+    ctx: Context,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let stream = load_stream(deps.storage, id)?;
+    let amount = stream.withdrawable(env.block.time)?;
+
+    payment_streams().remove(deps.storage, id)?;
+
+    let mut resp = Response::new().add_attributes(vec![
+        attr("action", "cancel_stream"),
+        attr("id", id.to_string()),
+        attr("recipient", stream.recipient.to_string()),
+        attr("settled", amount.to_string()),
+    ]);
+
+    if !amount.is_zero() {
+        resp = resp.add_submessage(build_mint_submsg(
+            deps.storage,
+            &env,
+            &stream.recipient,
+            amount,
+        )?);
+    }
+
+    Ok(resp)
+}
+
+fn load_stream(
+    storage: &dyn Storage,
+    id: u64,
+) -> Result<PaymentStream, ContractError> {
+    payment_streams()
+        .may_load(storage, id)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: format!("stream {} not found", id),
+        })
+}
@@ -0,0 +1,347 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{Contribution, Raise, RaisePricing, RaiseState},
+        storage::{
+            DENOM_METADATA, MANAGER, RAISES, RAISE_CONTRIBUTIONS, RAISE_LATEST_ROUND, RAISE_STATES,
+        },
+    },
+};
+use cosmwasm_std::{
+    attr, Addr, BankMsg, Coin, OverflowError, OverflowOperation, Response, StdError, Storage,
+    Timestamp, Uint128,
+};
+use cw_utils::must_pay;
+
+use super::{mint::build_mint_submsg, Context};
+
+#[allow(clippy::too_many_arguments)]
+pub fn exec_configure_raise(
+    ctx: Context,
+    denom: String,
+    soft_cap: Uint128,
+    hard_cap: Option<Uint128>,
+    start: Timestamp,
+    end: Timestamp,
+    pricing: RaisePricing,
+    treasury: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+
+    // Earlier rounds stay settleable, so a raise only has to have ended
+    let prev_round = RAISE_LATEST_ROUND.may_load(deps.storage)?;
+    if let Some(prev_round) = prev_round {
+        let (prev_raise, state) = load_raise(deps.storage, Some(prev_round))?;
+        if !state.total_contributed.is_zero() && !prev_raise.is_ended(env.block.time) {
+            return Err(ContractError::ValidationError {
+                reason: "cannot reconfigure a raise until it has ended".to_owned(),
+            });
+        }
+    }
+    let round = prev_round.map(|round| round + 1).unwrap_or(1);
+
+    if end <= start {
+        return Err(ContractError::ValidationError {
+            reason: "raise must end after it starts".to_owned(),
+        });
+    }
+
+    if hard_cap.map(|cap| cap < soft_cap).unwrap_or(false) {
+        return Err(ContractError::ValidationError {
+            reason: "hard cap must be at least the soft cap".to_owned(),
+        });
+    }
+
+    let is_valid_pricing = match &pricing {
+        RaisePricing::FixedPrice { price_per_token } => !price_per_token.is_zero(),
+        RaisePricing::ProRata { amount } => !amount.is_zero(),
+    };
+    if !is_valid_pricing {
+        return Err(ContractError::ValidationError {
+            reason: "raise price or amount must be non-zero".to_owned(),
+        });
+    }
+
+    let treasury = match treasury {
+        Some(addr) => deps.api.addr_validate(addr.as_str())?,
+        None => MANAGER.load(deps.storage)?,
+    };
+
+    let decimals = DENOM_METADATA.load(deps.storage)?.decimals;
+    let token_unit = Uint128::from(10u128)
+        .checked_pow(decimals)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    RAISES.save(
+        deps.storage,
+        round,
+        &Raise {
+            round,
+            denom: denom.to_owned(),
+            soft_cap,
+            hard_cap,
+            start,
+            end,
+            pricing,
+            token_unit,
+            treasury: treasury.to_owned(),
+        },
+    )?;
+    RAISE_STATES.save(deps.storage, round, &RaiseState::default())?;
+    RAISE_LATEST_ROUND.save(deps.storage, &round)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "configure_raise"),
+        attr("round", round.to_string()),
+        attr("denom", denom),
+        attr("soft_cap", soft_cap.to_string()),
+        attr("treasury", treasury.to_string()),
+    ]))
+}
+
+/// Escrow the attached funds as a contribution to the raise, refunding
+/// whatever exceeds the hard cap
+pub fn exec_contribute(
+    ctx: Context,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let recipient = ctx.recipient_or_sender(recipient)?;
+    let Context { deps, env, info } = ctx;
+    let (raise, mut state) = load_raise(deps.storage, None)?;
+
+    if !raise.is_open(env.block.time) {
+        return Err(ContractError::ValidationError {
+            reason: "raise is not open".to_owned(),
+        });
+    }
+
+    let payment = must_pay(&info, &raise.denom)?;
+    let accepted = match raise.hard_cap {
+        Some(hard_cap) => payment.min(hard_cap.saturating_sub(state.total_contributed)),
+        None => payment,
+    };
+    let refund = payment - accepted;
+
+    if accepted.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "raise hard cap reached".to_owned(),
+        });
+    }
+
+    let mut contribution = RAISE_CONTRIBUTIONS
+        .may_load(deps.storage, (raise.round, &recipient))?
+        .unwrap_or_default();
+
+    if contribution.amount.is_zero() {
+        state.contributor_count = state.contributor_count.checked_add(1).ok_or_else(|| {
+            ContractError::Std(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+                state.contributor_count,
+                1,
+            )))
+        })?;
+    }
+    contribution.amount = contribution
+        .amount
+        .checked_add(accepted)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.total_contributed = state
+        .total_contributed
+        .checked_add(accepted)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    RAISE_CONTRIBUTIONS.save(deps.storage, (raise.round, &recipient), &contribution)?;
+    RAISE_STATES.save(deps.storage, raise.round, &state)?;
+
+    let mut resp = Response::new().add_attributes(vec![
+        attr("action", "contribute"),
+        attr("contributor", info.sender.to_string()),
+        attr("recipient", recipient.to_string()),
+        attr("amount", accepted.to_string()),
+    ]);
+
+    if !refund.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(refund.u128(), raise.denom.to_owned())],
+        });
+    }
+
+    Ok(resp)
+}
+
+/// Mint the sender's allotment of a successful raise
+pub fn exec_claim(
+    ctx: Context,
+    round: Option<u64>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let (raise, mut state) = load_raise(deps.storage, round)?;
+
+    ensure_outcome(&raise, &state, env.block.time, true)?;
+
+    let mut contribution = load_unsettled_contribution(deps.storage, &raise, &info.sender)?;
+    let amount = raise.allotment(contribution.amount, state.total_contributed);
+
+    contribution.claimed = true;
+    state.amount_claimed = state
+        .amount_claimed
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.settled_count = state.settled_count.checked_add(1).ok_or_else(|| {
+        ContractError::Std(StdError::overflow(OverflowError::new(
+            OverflowOperation::Add,
+            state.settled_count,
+            1,
+        )))
+    })?;
+
+    RAISE_CONTRIBUTIONS.save(deps.storage, (raise.round, &info.sender), &contribution)?;
+    RAISE_STATES.save(deps.storage, raise.round, &state)?;
+
+    let mut resp = Response::new().add_attributes(vec![
+        attr("action", "claim"),
+        attr("round", raise.round.to_string()),
+        attr("recipient", info.sender.to_string()),
+        attr("amount", amount.to_string()),
+    ]);
+
+    if !amount.is_zero() {
+        resp = resp.add_submessage(build_mint_submsg(deps.storage, &env, &info.sender, amount)?);
+    }
+
+    Ok(resp)
+}
+
+/// Return the sender's contribution to a raise that missed its soft cap
+pub fn exec_refund(
+    ctx: Context,
+    round: Option<u64>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let (raise, mut state) = load_raise(deps.storage, round)?;
+
+    ensure_outcome(&raise, &state, env.block.time, false)?;
+
+    let mut contribution = load_unsettled_contribution(deps.storage, &raise, &info.sender)?;
+
+    contribution.refunded = true;
+    state.amount_refunded = state
+        .amount_refunded
+        .checked_add(contribution.amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.settled_count = state.settled_count.checked_add(1).ok_or_else(|| {
+        ContractError::Std(StdError::overflow(OverflowError::new(
+            OverflowOperation::Add,
+            state.settled_count,
+            1,
+        )))
+    })?;
+
+    RAISE_CONTRIBUTIONS.save(deps.storage, (raise.round, &info.sender), &contribution)?;
+    RAISE_STATES.save(deps.storage, raise.round, &state)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "refund"),
+            attr("round", raise.round.to_string()),
+            attr("recipient", info.sender.to_string()),
+            attr("amount", contribution.amount.to_string()),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(contribution.amount.u128(), raise.denom)],
+        }))
+}
+
+/// Release the escrowed proceeds of a successful raise to the treasury
+pub fn exec_withdraw_raise_proceeds(
+    ctx: Context,
+    round: Option<u64>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let (raise, mut state) = load_raise(deps.storage, round)?;
+
+    ensure_outcome(&raise, &state, env.block.time, true)?;
+
+    if state.proceeds_withdrawn {
+        return Err(ContractError::ValidationError {
+            reason: "raise proceeds already withdrawn".to_owned(),
+        });
+    }
+
+    state.proceeds_withdrawn = true;
+    RAISE_STATES.save(deps.storage, raise.round, &state)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "withdraw_raise_proceeds"),
+            attr("round", raise.round.to_string()),
+            attr("treasury", raise.treasury.to_string()),
+            attr("amount", state.total_contributed.to_string()),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: raise.treasury.to_string(),
+            amount: vec![Coin::new(state.total_contributed.u128(), raise.denom)],
+        }))
+}
+
+/// Load the raise of `round`, or the latest, with its totals
+pub fn load_raise(
+    storage: &dyn Storage,
+    round: Option<u64>,
+) -> Result<(Raise, RaiseState), ContractError> {
+    let round = match round {
+        Some(round) => Some(round),
+        None => RAISE_LATEST_ROUND.may_load(storage)?,
+    };
+    let raise = match round {
+        Some(round) => RAISES.may_load(storage, round)?,
+        None => None,
+    }
+    .ok_or_else(|| ContractError::ValidationError {
+        reason: "no raise configured".to_owned(),
+    })?;
+    let state = RAISE_STATES.load(storage, raise.round)?;
+    Ok((raise, state))
+}
+
+/// Ensure the raise has ended with the expected outcome
+fn ensure_outcome(
+    raise: &Raise,
+    state: &RaiseState,
+    time: Timestamp,
+    successful: bool,
+) -> Result<(), ContractError> {
+    if !raise.is_ended(time) {
+        return Err(ContractError::ValidationError {
+            reason: "raise has not ended".to_owned(),
+        });
+    }
+    if (state.total_contributed >= raise.soft_cap) != successful {
+        return Err(ContractError::ValidationError {
+            reason: if successful {
+                "raise did not reach its soft cap".to_owned()
+            } else {
+                "raise reached its soft cap".to_owned()
+            },
+        });
+    }
+    Ok(())
+}
+
+fn load_unsettled_contribution(
+    storage: &dyn Storage,
+    raise: &Raise,
+    contributor: &Addr,
+) -> Result<Contribution, ContractError> {
+    let contribution = RAISE_CONTRIBUTIONS
+        .may_load(storage, (raise.round, contributor))?
+        .unwrap_or_default();
+    if contribution.amount.is_zero() || contribution.claimed || contribution.refunded {
+        return Err(ContractError::ValidationError {
+            reason: "nothing to settle".to_owned(),
+        });
+    }
+    Ok(contribution)
+}
@@ -0,0 +1,77 @@
+use crate::{
+    error::ContractError,
+    msg::Cw20HookMsg,
+    state::storage::{CW20_AMOUNT_WRAPPED, CW20_MIGRATION, CW20_WRAPPER},
+};
+use cosmwasm_std::{attr, from_json, Addr, Response, StdError, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use super::{migration::burn_legacy_tokens, mint::build_backed_mint_submsg, Context};
+
+pub fn exec_receive(
+    ctx: Context,
+    msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let is_wrapper_token = CW20_WRAPPER
+        .may_load(ctx.deps.storage)?
+        .map(|w| w.address == ctx.info.sender)
+        .unwrap_or(false);
+
+    let is_legacy_token = CW20_MIGRATION
+        .may_load(ctx.deps.storage)?
+        .map(|m| m.token == ctx.info.sender)
+        .unwrap_or(false);
+
+    if is_wrapper_token {
+        match from_json(&msg.msg)? {
+            Cw20HookMsg::Wrap { recipient } => {
+                let sender = ctx.deps.api.addr_validate(&msg.sender)?;
+                wrap(ctx, recipient.unwrap_or(sender), msg.amount)
+            },
+        }
+    } else if is_legacy_token {
+        let owner = ctx.deps.api.addr_validate(&msg.sender)?;
+        burn_legacy_tokens(ctx, owner, msg.amount)
+    } else {
+        Err(ContractError::NotAuthorized {
+            reason: format!("unrecognized cw20 token {}", ctx.info.sender),
+        })
+    }
+}
+
+/// Mint the tokenfactory equivalent of cw20 tokens sent to this contract
+fn wrap(
+    ctx: Context,
+    recipient: Addr,
+    cw20_amount: Uint128,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let wrapper = CW20_WRAPPER.load(deps.storage)?;
+    let recipient = deps.api.addr_validate(recipient.as_str())?;
+    let amount = wrapper.conversion.to_native(cw20_amount)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "nothing to wrap".to_owned(),
+        });
+    }
+
+    CW20_AMOUNT_WRAPPED.update(deps.storage, |n| -> Result<_, ContractError> {
+        n.checked_add(cw20_amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))
+    })?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "wrap"),
+            attr("recipient", recipient.to_string()),
+            attr("cw20_amount", cw20_amount.to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessage(build_backed_mint_submsg(
+            deps.storage,
+            &env,
+            &recipient,
+            amount,
+        )?))
+}
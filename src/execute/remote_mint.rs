@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    error::ContractError,
+    msg::IbcLifecycleComplete,
+    state::{
+        models::{MintDelivery, PendingMint, RemoteTransfer},
+        storage::{FULL_DENOM, REMOTE_TRANSFERS, REMOTE_TRANSFER_IN_FLIGHT},
+        DEFAULT_IBC_TIMEOUT_SECONDS, REMOTE_TRANSFER_REPLY_ID,
+    },
+    tf::cosmos::{
+        denom::Coin as ProtoCoin,
+        transfer::{MsgTransfer, MsgTransferResponse},
+    },
+};
+use cosmwasm_std::{
+    attr, from_json, Addr, BankMsg, Coin, DepsMut, Env, Reply, Response, StdError, Storage, SubMsg,
+    SubMsgResult, Uint128,
+};
+use prost::Message;
+use serde::de::IgnoredAny;
+
+use super::{
+    burn::build_burn_submsgs,
+    mint::{build_pending_mint_submsg, mint_fee_for},
+    Context,
+};
+
+/// Mint and send the tokens over IBC to a recipient on another chain
+#[allow(clippy::too_many_arguments)]
+pub fn exec_mint_to_remote(
+    ctx: Context,
+    channel_id: String,
+    remote_recipient: String,
+    amount: Uint128,
+    timeout: Option<u64>,
+    memo: Option<String>,
+    refund_address: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+
+    if channel_id.is_empty() || remote_recipient.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "channel_id and remote_recipient are required".to_owned(),
+        });
+    }
+
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "amount must be non-zero".to_owned(),
+        });
+    }
+
+    let refund_address = refund_address
+        .map(|addr| deps.api.addr_validate(addr.as_str()))
+        .transpose()?;
+    let memo = with_ibc_callback(memo, &env.contract.address)?;
+    let fee = mint_fee_for(deps.storage, amount)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "mint_to_remote"),
+            attr("channel_id", channel_id.to_owned()),
+            attr("remote_recipient", remote_recipient.to_owned()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessage(build_pending_mint_submsg(
+            deps.storage,
+            &env,
+            PendingMint {
+                amount,
+                fee,
+                delivery: MintDelivery::IbcTransfer {
+                    channel_id,
+                    remote_recipient,
+                    timeout_seconds: timeout.unwrap_or(DEFAULT_IBC_TIMEOUT_SECONDS),
+                    memo,
+                    refund_address,
+                },
+            },
+        )?))
+}
+
+/// Build the ICS-20 transfer of freshly minted tokens. Its reply records the
+/// packet sequence so that lifecycle callbacks can be matched to it.
+pub fn build_remote_transfer_submsg(
+    storage: &mut dyn Storage,
+    env: &Env,
+    denom: &str,
+    transfer: RemoteTransfer,
+    timeout_seconds: u64,
+    memo: String,
+) -> Result<SubMsg, ContractError> {
+    let msg = MsgTransfer {
+        source_port: "transfer".to_owned(),
+        source_channel: transfer.channel_id.to_owned(),
+        token: Some(ProtoCoin {
+            denom: denom.to_owned(),
+            amount: transfer.amount.to_string(),
+        }),
+        sender: env.contract.address.to_string(),
+        receiver: transfer.remote_recipient.to_owned(),
+        timeout_height: None,
+        timeout_timestamp: env.block.time.plus_seconds(timeout_seconds).nanos(),
+        memo,
+    };
+
+    REMOTE_TRANSFER_IN_FLIGHT.save(storage, &transfer)?;
+
+    Ok(SubMsg::reply_on_success(msg, REMOTE_TRANSFER_REPLY_ID))
+}
+
+pub fn handle_remote_transfer_reply(
+    deps: DepsMut,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let response = match reply.result {
+        SubMsgResult::Ok(response) => response,
+        SubMsgResult::Err(e) => return Err(ContractError::Std(StdError::generic_err(e))),
+    };
+
+    // Prefer the send_packet event, falling back on the msg response data
+    let sequence = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "send_packet")
+        .flat_map(|e| e.attributes.iter())
+        .find(|a| a.key == "packet_sequence")
+        .and_then(|a| a.value.parse::<u64>().ok())
+        .or_else(|| {
+            response
+                .data
+                .and_then(|data| MsgTransferResponse::decode(data.as_slice()).ok())
+                .map(|r| r.sequence)
+        })
+        .ok_or_else(|| StdError::generic_err("missing IBC transfer sequence"))?;
+
+    let mut transfer = REMOTE_TRANSFER_IN_FLIGHT.load(deps.storage)?;
+    REMOTE_TRANSFER_IN_FLIGHT.remove(deps.storage);
+
+    transfer.sequence = sequence;
+    REMOTE_TRANSFERS.save(
+        deps.storage,
+        (transfer.channel_id.as_str(), sequence),
+        &transfer,
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "remote_transfer_sent"),
+        attr("channel_id", transfer.channel_id),
+        attr("sequence", sequence.to_string()),
+    ]))
+}
+
+/// Handle an ibc-hooks callback for a remote transfer. On ack failure or
+/// timeout the tokens are refunded to this contract, so they are forwarded to
+/// the transfer's refund address or burned.
+pub fn sudo_ibc_lifecycle(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcLifecycleComplete,
+) -> Result<Response, ContractError> {
+    let (channel, sequence, is_success) = match msg {
+        IbcLifecycleComplete::IbcAck {
+            channel,
+            sequence,
+            success,
+            ..
+        } => (channel, sequence, success),
+        IbcLifecycleComplete::IbcTimeout { channel, sequence } => (channel, sequence, false),
+    };
+
+    let transfer = match REMOTE_TRANSFERS.may_load(deps.storage, (channel.as_str(), sequence))? {
+        Some(transfer) => transfer,
+        None => return Ok(Response::new()),
+    };
+
+    REMOTE_TRANSFERS.remove(deps.storage, (channel.as_str(), sequence));
+
+    let mut resp = Response::new().add_attributes(vec![
+        attr("action", "remote_transfer_complete"),
+        attr("channel_id", channel),
+        attr("sequence", sequence.to_string()),
+        attr("success", is_success.to_string()),
+    ]);
+
+    if !is_success {
+        resp = match transfer.refund_address {
+            Some(refund_address) => resp
+                .add_attribute("refunded_to", refund_address.to_string())
+                .add_message(BankMsg::Send {
+                    to_address: refund_address.to_string(),
+                    amount: vec![Coin::new(
+                        transfer.amount.u128(),
+                        FULL_DENOM.load(deps.storage)?,
+                    )],
+                }),
+            None => resp
+                .add_attribute("burned", transfer.amount.to_string())
+                .add_submessages(build_burn_submsgs(
+                    deps.storage,
+                    &env,
+                    &env.contract.address,
+                    transfer.amount,
+                )?),
+        };
+    }
+
+    Ok(resp)
+}
+
+/// Add this contract as the ibc-hooks callback to a JSON object memo, so that
+/// it receives lifecycle callbacks for the transfer
+fn with_ibc_callback(
+    memo: Option<String>,
+    contract: &Addr,
+) -> Result<String, ContractError> {
+    let callback = format!("\"ibc_callback\":\"{}\"", contract);
+    let memo = match memo.map(|m| m.trim().to_owned()).filter(|m| !m.is_empty()) {
+        Some(memo) => memo,
+        None => return Ok(format!("{{{}}}", callback)),
+    };
+
+    let fields: BTreeMap<String, IgnoredAny> =
+        from_json(memo.as_bytes()).map_err(|_| ContractError::ValidationError {
+            reason: "memo must be a JSON object".to_owned(),
+        })?;
+
+    if fields.contains_key("ibc_callback") {
+        return Err(ContractError::ValidationError {
+            reason: "memo cannot set ibc_callback".to_owned(),
+        });
+    }
+
+    if fields.is_empty() {
+        Ok(format!("{{{}}}", callback))
+    } else {
+        Ok(format!("{{{},{}", callback, &memo[1..]))
+    }
+}
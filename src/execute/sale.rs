@@ -0,0 +1,173 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::Sale,
+        storage::{DENOM_METADATA, MANAGER, SALE, SALE_PURCHASES, SALE_STATS},
+    },
+};
+use cosmwasm_std::{attr, Addr, BankMsg, Coin, Response, StdError, Timestamp, Uint128, Uint256};
+use cw_utils::must_pay;
+
+use super::{mint::build_mint_submsg, Context};
+
+#[allow(clippy::too_many_arguments)]
+pub fn exec_configure_sale(
+    ctx: Context,
+    price_denom: String,
+    price_per_token: Uint128,
+    start: Option<Timestamp>,
+    end: Option<Timestamp>,
+    max_per_address: Option<Uint128>,
+    hard_cap: Option<Uint128>,
+    treasury: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+
+    if price_per_token.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "sale price must be non-zero".to_owned(),
+        });
+    }
+
+    if let Some(end) = end {
+        if end <= start.unwrap_or(env.block.time) {
+            return Err(ContractError::ValidationError {
+                reason: "sale must end after it starts".to_owned(),
+            });
+        }
+    }
+
+    let treasury = match treasury {
+        Some(addr) => deps.api.addr_validate(addr.as_str())?,
+        None => MANAGER.load(deps.storage)?,
+    };
+
+    let round = SALE
+        .may_load(deps.storage)?
+        .map(|sale| sale.round + 1)
+        .unwrap_or(1);
+
+    let decimals = DENOM_METADATA.load(deps.storage)?.decimals;
+    let token_unit = Uint128::from(10u128)
+        .checked_pow(decimals)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    SALE.save(
+        deps.storage,
+        &Sale {
+            round,
+            price_denom: price_denom.to_owned(),
+            price_per_token,
+            token_unit,
+            start,
+            end,
+            max_per_address,
+            hard_cap,
+            treasury: treasury.to_owned(),
+        },
+    )?;
+
+    // Caps apply per round, so a new round starts from fresh stats
+    SALE_STATS.save(deps.storage, &Default::default())?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "configure_sale"),
+        attr("round", round.to_string()),
+        attr("price_denom", price_denom),
+        attr("price_per_token", price_per_token.to_string()),
+        attr("treasury", treasury.to_string()),
+    ]))
+}
+
+/// Buy as many tokens as the attached funds pay for, subject to the sale's
+/// caps. Any unspent funds are refunded.
+pub fn exec_buy(
+    ctx: Context,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let recipient = ctx.recipient_or_sender(recipient)?;
+    let Context { deps, env, info } = ctx;
+    let sale = SALE
+        .may_load(deps.storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "no sale configured".to_owned(),
+        })?;
+
+    if !sale.is_active(env.block.time) {
+        return Err(ContractError::ValidationError {
+            reason: "sale is not active".to_owned(),
+        });
+    }
+
+    let payment = must_pay(&info, &sale.price_denom)?;
+    let mut stats = SALE_STATS.load(deps.storage)?;
+    let prev_purchased = SALE_PURCHASES
+        .may_load(deps.storage, (sale.round, &recipient))?
+        .unwrap_or_default();
+
+    let mut amount = payment.multiply_ratio(sale.token_unit, sale.price_per_token);
+    if let Some(hard_cap) = sale.hard_cap {
+        amount = amount.min(hard_cap.saturating_sub(stats.amount_sold));
+    }
+    if let Some(max_per_address) = sale.max_per_address {
+        amount = amount.min(max_per_address.saturating_sub(prev_purchased));
+    }
+
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "payment does not buy any tokens".to_owned(),
+        });
+    }
+
+    // Round the cost up so that the buyer never underpays
+    let mut cost = amount.multiply_ratio(sale.price_per_token, sale.token_unit);
+    if Uint256::from(cost) * Uint256::from(sale.token_unit) < amount.full_mul(sale.price_per_token)
+    {
+        cost += Uint128::one();
+    }
+    let refund = payment - cost;
+
+    if prev_purchased.is_zero() {
+        stats.buyer_count += 1;
+    }
+    stats.amount_sold = stats
+        .amount_sold
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    stats.amount_raised = stats
+        .amount_raised
+        .checked_add(cost)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    SALE_STATS.save(deps.storage, &stats)?;
+    SALE_PURCHASES.save(
+        deps.storage,
+        (sale.round, &recipient),
+        &prev_purchased
+            .checked_add(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?,
+    )?;
+
+    let mut resp = Response::new()
+        .add_attributes(vec![
+            attr("action", "buy"),
+            attr("buyer", info.sender.to_string()),
+            attr("recipient", recipient.to_string()),
+            attr("amount", amount.to_string()),
+            attr("cost", cost.to_string()),
+        ])
+        .add_submessage(build_mint_submsg(deps.storage, &env, &recipient, amount)?)
+        .add_message(BankMsg::Send {
+            to_address: sale.treasury.to_string(),
+            amount: vec![Coin::new(cost.u128(), sale.price_denom.to_owned())],
+        });
+
+    if !refund.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(refund.u128(), sale.price_denom.to_owned())],
+        });
+    }
+
+    Ok(resp)
+}
@@ -0,0 +1,144 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::ScheduledMint,
+        storage::{scheduled_mints, SCHEDULED_MINT_ID_COUNTER},
+        DEFAULT_LIMIT, MAX_LIMIT,
+    },
+};
+use cosmwasm_std::{
+    attr, Addr, Env, Order, Response, StdResult, Storage, SubMsg, Timestamp, Uint128,
+};
+use cw_storage_plus::Bound;
+
+use super::{
+    mint::{build_mint_submsg, remaining_supply, take_from_budget},
+    Context,
+};
+
+pub fn exec_schedule_mint(
+    ctx: Context,
+    recipient: Addr,
+    amount: Uint128,
+    not_before: Timestamp,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let recipient = deps.api.addr_validate(recipient.as_str())?;
+
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "amount must be non-zero".to_owned(),
+        });
+    }
+
+    let id = SCHEDULED_MINT_ID_COUNTER
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    SCHEDULED_MINT_ID_COUNTER.save(deps.storage, &id)?;
+
+    scheduled_mints().save(
+        deps.storage,
+        id,
+        &ScheduledMint {
+            id,
+            recipient: recipient.to_owned(),
+            amount,
+            not_before,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "schedule_mint"),
+        attr("id", id.to_string()),
+        attr("recipient", recipient.to_string()),
+        attr("amount", amount.to_string()),
+        attr("not_before", not_before.to_string()),
+    ]))
+}
+
+/// Execute up to `limit` scheduled mints whose time has passed, oldest first
+pub fn exec_execute_due_mints(
+    ctx: Context,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let mut budget = remaining_supply(deps.storage)?;
+    let (submsgs, deferred) = execute_due_mints(deps.storage, &env, limit as usize, &mut budget)?;
+
+    if submsgs.is_empty() && deferred.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "no scheduled mints are due".to_owned(),
+        });
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "execute_due_mints"),
+            attr("count", submsgs.len().to_string()),
+            attr("deferred", deferred.to_string()),
+        ])
+        .add_submessages(submsgs))
+}
+
+pub fn exec_cancel_scheduled_mint(
+    ctx: Context,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+
+    if scheduled_mints().may_load(deps.storage, id)?.is_none() {
+        return Err(ContractError::ValidationError {
+            reason: format!("scheduled mint {} not found", id),
+        });
+    }
+
+    scheduled_mints().remove(deps.storage, id)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "cancel_scheduled_mint"),
+        attr("id", id.to_string()),
+    ]))
+}
+
+/// Execute up to `limit` due scheduled mints, returning their mint submsgs
+/// and the amount deferred. Mints are clamped to what fits in `budget`, and
+/// any remainder stays scheduled until the max supply allows it.
+pub fn execute_due_mints(
+    storage: &mut dyn Storage,
+    env: &Env,
+    limit: usize,
+    budget: &mut Option<Uint128>,
+) -> Result<(Vec<SubMsg>, Uint128), ContractError> {
+    let due = scheduled_mints()
+        .idx
+        .not_before
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive((env.block.time.seconds(), u64::MAX))),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| r.map(|(_, m)| m))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut submsgs: Vec<SubMsg> = Vec::with_capacity(due.len());
+    let mut deferred = Uint128::zero();
+    for mut m in due {
+        let amount = take_from_budget(storage, m.amount, budget)?;
+        if amount < m.amount {
+            m.amount -= amount;
+            deferred += m.amount;
+            scheduled_mints().save(storage, m.id, &m)?;
+        } else {
+            scheduled_mints().remove(storage, m.id)?;
+        }
+        if !amount.is_zero() {
+            submsgs.push(build_mint_submsg(storage, env, &m.recipient, amount)?);
+        }
+    }
+
+    Ok((submsgs, deferred))
+}
@@ -0,0 +1,53 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{Cw20Wrapper, DecimalConversion},
+        storage::{CW20_AMOUNT_WRAPPED, CW20_WRAPPER, DENOM_METADATA},
+    },
+};
+use cosmwasm_std::{attr, Addr, Response, Uint128};
+use cw20::{Cw20QueryMsg, TokenInfoResponse};
+
+use super::Context;
+
+pub fn exec_set_cw20_wrapper(
+    ctx: Context,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let address = deps.api.addr_validate(address.as_str())?;
+
+    // Swapping tokens out from under existing holders would leave them unbacked
+    if !CW20_AMOUNT_WRAPPED
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .is_zero()
+    {
+        return Err(ContractError::ValidationError {
+            reason: "cannot change cw20 wrapper while wrapped tokens are outstanding".to_owned(),
+        });
+    }
+
+    let token_info: TokenInfoResponse = deps
+        .querier
+        .query_wasm_smart(address.to_owned(), &Cw20QueryMsg::TokenInfo {})?;
+
+    let native_decimals = DENOM_METADATA.load(deps.storage)?.decimals;
+
+    CW20_WRAPPER.save(
+        deps.storage,
+        &Cw20Wrapper {
+            address: address.to_owned(),
+            conversion: DecimalConversion {
+                cw20_decimals: token_info.decimals as u32,
+                native_decimals,
+            },
+        },
+    )?;
+    CW20_AMOUNT_WRAPPED.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_cw20_wrapper"),
+        attr("cw20_address", address.to_string()),
+    ]))
+}
@@ -0,0 +1,26 @@
+use crate::{error::ContractError, state::storage::MAX_SUPPLY};
+use cosmwasm_std::{attr, Response, Uint128};
+
+use super::{mint::net_supply, Context};
+
+pub fn exec_set_max_supply(
+    ctx: Context,
+    max_supply: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let mut attrs = vec![attr("action", "set_max_supply")];
+
+    if let Some(max_supply) = max_supply {
+        if net_supply(deps.storage)? > max_supply.into() {
+            return Err(ContractError::ValidationError {
+                reason: "max supply is less than the current supply".to_owned(),
+            });
+        }
+        MAX_SUPPLY.save(deps.storage, &max_supply)?;
+        attrs.push(attr("max_supply", max_supply.to_string()));
+    } else {
+        MAX_SUPPLY.remove(deps.storage);
+    }
+
+    Ok(Response::new().add_attributes(attrs))
+}
@@ -0,0 +1,23 @@
+use crate::{error::ContractError, state::models::MintFee, state::storage::MINT_FEE};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+pub fn exec_set_mint_fee(
+    ctx: Context,
+    mint_fee: Option<MintFee>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let mut attrs = vec![attr("action", "set_mint_fee")];
+
+    if let Some(mint_fee) = mint_fee {
+        mint_fee.validate(deps.api)?;
+        MINT_FEE.save(deps.storage, &mint_fee)?;
+        attrs.push(attr("bps", mint_fee.bps.to_string()));
+        attrs.push(attr("recipient", mint_fee.recipient.to_string()));
+    } else {
+        MINT_FEE.remove(deps.storage);
+    }
+
+    Ok(Response::new().add_attributes(attrs))
+}
@@ -0,0 +1,460 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{StakingConfig, StakingValidator, UnbondingClaim},
+        storage::{FULL_DENOM, STAKING_CONFIG, STAKING_STATE, UNBONDING_CLAIMS},
+    },
+    tf::cosmos::staking::QueryDelegatorUnbondingDelegationsRequest,
+};
+use cosmwasm_std::{
+    attr, Addr, BankMsg, Coin, CosmosMsg, DistributionMsg, Env, QuerierWrapper, Response,
+    StakingMsg, StdError, Storage, Uint128,
+};
+use cw_utils::must_pay;
+
+use super::{burn::build_burn_submsgs, mint::build_backed_mint_submsg, Context};
+
+pub fn exec_configure_staking(
+    ctx: Context,
+    bond_denom: Option<String>,
+    validators: Vec<StakingValidator>,
+    unbonding_period: u64,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let bond_denom = match bond_denom {
+        Some(denom) => denom,
+        None => deps.querier.query_bonded_denom()?,
+    };
+
+    if validators.is_empty() || validators.iter().any(|v| v.weight == 0) {
+        return Err(ContractError::ValidationError {
+            reason: "validators must be non-empty with non-zero weights".to_owned(),
+        });
+    }
+
+    for (i, v) in validators.iter().enumerate() {
+        if validators[..i]
+            .iter()
+            .any(|other| other.address == v.address)
+        {
+            return Err(ContractError::ValidationError {
+                reason: format!("duplicate validator {}", v.address),
+            });
+        }
+        if deps.querier.query_validator(&v.address)?.is_none() {
+            return Err(ContractError::ValidationError {
+                reason: format!("unknown validator {}", v.address),
+            });
+        }
+    }
+
+    if unbonding_period == 0 {
+        return Err(ContractError::ValidationError {
+            reason: "unbonding period must be non-zero".to_owned(),
+        });
+    }
+
+    let state = STAKING_STATE.may_load(deps.storage)?.unwrap_or_default();
+
+    // The validator set may change at any time since undelegation works off
+    // actual delegations, but the bond denom is fixed once anything is staked
+    if let Some(config) = STAKING_CONFIG.may_load(deps.storage)? {
+        let is_in_use = !(state.total_supply.is_zero() && state.total_unbonding.is_zero());
+        if is_in_use && config.bond_denom != bond_denom {
+            return Err(ContractError::ValidationError {
+                reason: "cannot change bond denom while staked".to_owned(),
+            });
+        }
+    }
+
+    STAKING_CONFIG.save(
+        deps.storage,
+        &StakingConfig {
+            bond_denom: bond_denom.to_owned(),
+            validators: validators.to_owned(),
+            unbonding_period,
+        },
+    )?;
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "configure_staking"),
+        attr("bond_denom", bond_denom),
+        attr("validator_count", validators.len().to_string()),
+        attr("unbonding_period", unbonding_period.to_string()),
+    ]))
+}
+
+/// Delegate the attached bond denom and mint receipts at the current
+/// exchange rate
+pub fn exec_stake(
+    ctx: Context,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let recipient = ctx.recipient_or_sender(recipient)?;
+    let Context { deps, env, info } = ctx;
+    let config = load_staking_config(deps.storage)?;
+    let amount = must_pay(&info, &config.bond_denom)?;
+    let mut state = STAKING_STATE.load(deps.storage)?;
+
+    // Delegating would pay out pending rewards anyway, so withdraw them
+    // first to know how much to compound later. They back existing receipts
+    // and are counted before pricing the new ones.
+    let (withdraw_msgs, _, rewards) =
+        build_withdraw_reward_msgs(deps.querier, &env, &config.bond_denom)?;
+    state.idle_rewards = state
+        .idle_rewards
+        .checked_add(rewards)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    let receipts = state.receipts_for(amount)?;
+    if receipts.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "stake too small".to_owned(),
+        });
+    }
+
+    state.total_bonded = state
+        .total_bonded
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.total_supply = state
+        .total_supply
+        .checked_add(receipts)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "stake"),
+            attr("staker", info.sender.to_string()),
+            attr("recipient", recipient.to_string()),
+            attr("amount", amount.to_string()),
+            attr("receipts", receipts.to_string()),
+        ])
+        .add_messages(withdraw_msgs)
+        .add_messages(build_delegate_msgs(&config, amount))
+        .add_submessage(build_backed_mint_submsg(
+            deps.storage,
+            &env,
+            &recipient,
+            receipts,
+        )?))
+}
+
+/// Withdraw delegator rewards and delegate them, along with rewards withdrawn
+/// by earlier stakes and unstakes, raising the exchange rate for all receipt
+/// holders
+pub fn exec_compound(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let config = load_staking_config(deps.storage)?;
+    let mut state = STAKING_STATE.load(deps.storage)?;
+
+    let (mut msgs, delegated, rewards) =
+        build_withdraw_reward_msgs(deps.querier, &env, &config.bond_denom)?;
+
+    // Only rewards this contract has tracked are compounded, never other
+    // funds that happen to be held in the bond denom
+    let idle = state.idle_rewards;
+    let amount = rewards
+        .checked_add(idle)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "nothing to compound".to_owned(),
+        });
+    }
+
+    // Re-basing on actual delegations also accounts for any slashing
+    state.total_bonded = delegated
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.idle_rewards = Uint128::zero();
+
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    msgs.extend(build_delegate_msgs(&config, amount));
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "compound"),
+            attr("rewards", rewards.to_string()),
+            attr("idle", idle.to_string()),
+            attr("total_bonded", state.total_bonded.to_string()),
+            attr("exchange_rate", state.exchange_rate()?.to_string()),
+        ])
+        .add_messages(msgs))
+}
+
+/// Burn attached receipts and queue a claim for their share of the backing,
+/// undelegating the part that is bonded. The part held as idle rewards is
+/// already with the contract.
+pub fn exec_unstake(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let config = load_staking_config(deps.storage)?;
+    let receipts = must_pay(&info, &FULL_DENOM.load(deps.storage)?)?;
+    let mut state = STAKING_STATE.load(deps.storage)?;
+
+    if receipts > state.total_supply {
+        return Err(ContractError::ValidationError {
+            reason: "amount exceeds staking receipts outstanding".to_owned(),
+        });
+    }
+
+    // Undelegating would pay out pending rewards anyway, so withdraw them
+    // first to know how much to compound later
+    let (withdraw_msgs, _, rewards) =
+        build_withdraw_reward_msgs(deps.querier, &env, &config.bond_denom)?;
+    state.idle_rewards = state
+        .idle_rewards
+        .checked_add(rewards)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    let amount = state.bond_for(receipts)?;
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "unstake too small".to_owned(),
+        });
+    }
+    let from_idle = amount.multiply_ratio(state.idle_rewards, state.backing()?);
+    let undelegated = amount - from_idle;
+    let undelegate_msgs = if undelegated.is_zero() {
+        vec![]
+    } else {
+        build_undelegate_msgs(deps.querier, &env, &config.bond_denom, undelegated)?
+    };
+
+    state.total_supply -= receipts;
+    state.idle_rewards -= from_idle;
+    state.total_bonded = state
+        .total_bonded
+        .checked_sub(undelegated)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    state.total_unbonding = state
+        .total_unbonding
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    let release_at = env.block.time.plus_seconds(config.unbonding_period);
+
+    UNBONDING_CLAIMS.update(
+        deps.storage,
+        &info.sender,
+        |claims| -> Result<_, ContractError> {
+            let mut claims = claims.unwrap_or_default();
+            claims.push(UnbondingClaim { amount, release_at });
+            Ok(claims)
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "unstake"),
+            attr("staker", info.sender.to_string()),
+            attr("receipts", receipts.to_string()),
+            attr("amount", amount.to_string()),
+            attr("release_at", release_at.to_string()),
+        ])
+        .add_submessages(build_burn_submsgs(
+            deps.storage,
+            &env,
+            &info.sender,
+            receipts,
+        )?)
+        .add_messages(withdraw_msgs)
+        .add_messages(undelegate_msgs))
+}
+
+/// Pay out all of the sender's matured unbonding claims. Claims are paid only
+/// from tokens the staking module has returned, which may be later than the
+/// configured unbonding period.
+pub fn exec_claim_unbonded(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let config = load_staking_config(deps.storage)?;
+    let claims = UNBONDING_CLAIMS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+
+    let (matured, pending): (Vec<_>, Vec<_>) = claims
+        .into_iter()
+        .partition(|c| c.release_at <= env.block.time);
+
+    let amount: Uint128 = matured.iter().map(|c| c.amount).sum();
+    if amount.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "no matured unbonding claims".to_owned(),
+        });
+    }
+
+    // Other modes may hold the bond denom too, so the balance can't tell
+    // what has been returned. Whatever is owed and no longer unbonding with
+    // the staking module has been.
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let still_unbonding = query_unbonding(deps.querier, &env)?;
+    let returned = state.total_unbonding.saturating_sub(still_unbonding);
+    if amount > returned {
+        return Err(ContractError::ValidationError {
+            reason: format!(
+                "only {} unbonded tokens have been returned by the staking module",
+                returned
+            ),
+        });
+    }
+    state.total_unbonding -= amount;
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    if pending.is_empty() {
+        UNBONDING_CLAIMS.remove(deps.storage, &info.sender);
+    } else {
+        UNBONDING_CLAIMS.save(deps.storage, &info.sender, &pending)?;
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "claim_unbonded"),
+            attr("recipient", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(amount.u128(), config.bond_denom)],
+        }))
+}
+
+pub fn load_staking_config(storage: &dyn Storage) -> Result<StakingConfig, ContractError> {
+    STAKING_CONFIG
+        .may_load(storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "staking not configured".to_owned(),
+        })
+}
+
+/// Bond denom this contract is still owed by the staking module's unbonding
+/// queue
+fn query_unbonding(
+    querier: QuerierWrapper,
+    env: &Env,
+) -> Result<Uint128, ContractError> {
+    let resp = QueryDelegatorUnbondingDelegationsRequest {
+        delegator_addr: env.contract.address.to_string(),
+    }
+    .query(&querier)?;
+
+    let mut total = Uint128::zero();
+    for entry in resp
+        .unbonding_responses
+        .iter()
+        .flat_map(|u| u.entries.iter())
+    {
+        let balance = entry
+            .balance
+            .parse::<u128>()
+            .map_err(|e| ContractError::Std(StdError::generic_err(e.to_string())))?;
+        total = total
+            .checked_add(Uint128::new(balance))
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    }
+    Ok(total)
+}
+
+/// Withdraw rewards from every validator this contract delegates to,
+/// returning the withdraw messages, the total delegated and the rewards they
+/// pay out in the bond denom
+pub fn build_withdraw_reward_msgs(
+    querier: QuerierWrapper,
+    env: &Env,
+    bond_denom: &str,
+) -> Result<(Vec<CosmosMsg>, Uint128, Uint128), ContractError> {
+    let contract_addr = &env.contract.address;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut delegated = Uint128::zero();
+    let mut rewards = Uint128::zero();
+
+    for delegation in querier.query_all_delegations(contract_addr)? {
+        delegated = delegated
+            .checked_add(delegation.amount.amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        if let Some(full) = querier.query_delegation(contract_addr, &delegation.validator)? {
+            let reward = full
+                .accumulated_rewards
+                .iter()
+                .find(|c| c.denom == bond_denom)
+                .map(|c| c.amount)
+                .unwrap_or_default();
+            if !reward.is_zero() {
+                rewards = rewards
+                    .checked_add(reward)
+                    .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+                msgs.push(
+                    DistributionMsg::WithdrawDelegatorReward {
+                        validator: delegation.validator,
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
+    Ok((msgs, delegated, rewards))
+}
+
+fn build_delegate_msgs(
+    config: &StakingConfig,
+    amount: Uint128,
+) -> Vec<CosmosMsg> {
+    config
+        .split(amount)
+        .into_iter()
+        .map(|(validator, n)| {
+            StakingMsg::Delegate {
+                validator,
+                amount: Coin::new(n.u128(), config.bond_denom.to_owned()),
+            }
+            .into()
+        })
+        .collect()
+}
+
+/// Undelegate from the largest delegations first, so that the fewest
+/// validators are touched
+fn build_undelegate_msgs(
+    querier: QuerierWrapper,
+    env: &Env,
+    bond_denom: &str,
+    amount: Uint128,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let mut delegations = querier.query_all_delegations(&env.contract.address)?;
+    delegations.sort_by_key(|d| std::cmp::Reverse(d.amount.amount));
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut remaining = amount;
+
+    for delegation in delegations {
+        if remaining.is_zero() {
+            break;
+        }
+        let n = delegation.amount.amount.min(remaining);
+        if n.is_zero() {
+            continue;
+        }
+        remaining -= n;
+        msgs.push(
+            StakingMsg::Undelegate {
+                validator: delegation.validator,
+                amount: Coin::new(n.u128(), bond_denom),
+            }
+            .into(),
+        );
+    }
+
+    if !remaining.is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "insufficient delegations to unstake".to_owned(),
+        });
+    }
+
+    Ok(msgs)
+}
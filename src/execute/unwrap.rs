@@ -0,0 +1,51 @@
+use crate::{
+    error::ContractError,
+    state::storage::{CW20_AMOUNT_WRAPPED, CW20_WRAPPER, FULL_DENOM},
+};
+use cosmwasm_std::{attr, to_json_binary, Response, StdError, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use cw_utils::must_pay;
+
+use super::{burn::build_burn_submsgs, Context};
+
+/// Burn tokenfactory tokens sent with this msg, releasing the equivalent
+/// amount of the wrapped cw20 token back to the sender.
+pub fn exec_unwrap(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let wrapper =
+        CW20_WRAPPER
+            .may_load(deps.storage)?
+            .ok_or_else(|| ContractError::ValidationError {
+                reason: "cw20 wrapper not configured".to_owned(),
+            })?;
+
+    let amount = must_pay(&info, &FULL_DENOM.load(deps.storage)?)?;
+    let cw20_amount = wrapper.conversion.to_cw20(amount)?;
+
+    CW20_AMOUNT_WRAPPED.update(deps.storage, |n| -> Result<_, ContractError> {
+        n.checked_sub(cw20_amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))
+    })?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "unwrap"),
+            attr("recipient", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+            attr("cw20_amount", cw20_amount.to_string()),
+        ])
+        .add_submessages(build_burn_submsgs(
+            deps.storage,
+            &env,
+            &info.sender,
+            amount,
+        )?)
+        .add_message(WasmMsg::Execute {
+            contract_addr: wrapper.address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: info.sender.to_string(),
+                amount: cw20_amount,
+            })?,
+            funds: vec![],
+        }))
+}
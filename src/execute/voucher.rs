@@ -0,0 +1,111 @@
+use crate::{
+    error::ContractError,
+    msg::VoucherPayload,
+    state::{
+        models::{VoucherKeyType, VoucherSigner},
+        storage::{VOUCHER_NONCES, VOUCHER_SIGNER},
+    },
+};
+use cosmwasm_std::{
+    attr, to_json_vec, Addr, Binary, Empty, Response, StdError, Timestamp, Uint128,
+};
+use sha2::{Digest, Sha256};
+
+use super::{mint::build_mint_submsg, Context};
+
+pub fn exec_set_voucher_signer(
+    ctx: Context,
+    signer: Option<VoucherSigner>,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let mut attrs = vec![attr("action", "set_voucher_signer")];
+
+    if let Some(signer) = signer {
+        let is_valid_len = match signer.key_type {
+            VoucherKeyType::Secp256k1 => [33, 65].contains(&signer.public_key.len()),
+            VoucherKeyType::Ed25519 => signer.public_key.len() == 32,
+        };
+        if !is_valid_len {
+            return Err(ContractError::ValidationError {
+                reason: "invalid public key length for key type".to_owned(),
+            });
+        }
+        VOUCHER_SIGNER.save(deps.storage, &signer)?;
+        attrs.push(attr("public_key", signer.public_key.to_base64()));
+    } else {
+        VOUCHER_SIGNER.remove(deps.storage);
+    }
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Mint to the recipient of a voucher signed by the voucher signer. Anyone
+/// may submit it, so the backend does not pay gas.
+pub fn exec_redeem_voucher(
+    ctx: Context,
+    recipient: Addr,
+    amount: Uint128,
+    nonce: u64,
+    expires: Timestamp,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let Context { deps, env, .. } = ctx;
+    let signer =
+        VOUCHER_SIGNER
+            .may_load(deps.storage)?
+            .ok_or_else(|| ContractError::ValidationError {
+                reason: "voucher signer not set".to_owned(),
+            })?;
+
+    if env.block.time >= expires {
+        return Err(ContractError::ValidationError {
+            reason: "voucher expired".to_owned(),
+        });
+    }
+
+    if VOUCHER_NONCES.has(deps.storage, nonce) {
+        return Err(ContractError::ValidationError {
+            reason: format!("voucher nonce {} already used", nonce),
+        });
+    }
+
+    let payload = to_json_vec(&VoucherPayload {
+        chain_id: env.block.chain_id.to_owned(),
+        contract: env.contract.address.to_owned(),
+        recipient: recipient.to_owned(),
+        amount,
+        nonce,
+        expires,
+    })?;
+
+    let is_valid = match signer.key_type {
+        VoucherKeyType::Secp256k1 => {
+            deps.api
+                .secp256k1_verify(&Sha256::digest(&payload), &signature, &signer.public_key)
+        },
+        VoucherKeyType::Ed25519 => {
+            deps.api
+                .ed25519_verify(&payload, &signature, &signer.public_key)
+        },
+    }
+    .map_err(StdError::from)?;
+
+    if !is_valid {
+        return Err(ContractError::NotAuthorized {
+            reason: "invalid voucher signature".to_owned(),
+        });
+    }
+
+    let recipient = deps.api.addr_validate(recipient.as_str())?;
+
+    VOUCHER_NONCES.save(deps.storage, nonce, &Empty {})?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "redeem_voucher"),
+            attr("recipient", recipient.to_string()),
+            attr("amount", amount.to_string()),
+            attr("nonce", nonce.to_string()),
+        ])
+        .add_submessage(build_mint_submsg(deps.storage, &env, &recipient, amount)?))
+}
@@ -0,0 +1,129 @@
+use crate::{
+    error::ContractError,
+    state::{
+        models::{WrappedAsset, WrappedAssetStats},
+        storage::{FULL_DENOM, WRAPPED_ASSET, WRAPPED_ASSET_STATS},
+    },
+};
+use cosmwasm_std::{attr, Addr, BankMsg, Coin, Response, StdError, Storage};
+use cw_utils::must_pay;
+
+use super::{burn::build_burn_submsgs, mint::build_backed_mint_submsg, Context};
+
+pub fn exec_configure_wrapped_asset(
+    ctx: Context,
+    underlying_denom: String,
+) -> Result<Response, ContractError> {
+    let Context { deps, .. } = ctx;
+    let stats = WRAPPED_ASSET_STATS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+
+    // Switching the underlying denom would strand existing receipt holders
+    if !stats.outstanding().is_zero() {
+        return Err(ContractError::ValidationError {
+            reason: "cannot change underlying denom while receipts are outstanding".to_owned(),
+        });
+    }
+
+    if underlying_denom == FULL_DENOM.load(deps.storage)? {
+        return Err(ContractError::ValidationError {
+            reason: "underlying denom cannot be the receipt denom".to_owned(),
+        });
+    }
+
+    WRAPPED_ASSET.save(
+        deps.storage,
+        &WrappedAsset {
+            underlying_denom: underlying_denom.to_owned(),
+        },
+    )?;
+    WRAPPED_ASSET_STATS.save(deps.storage, &stats)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "configure_wrapped_asset"),
+        attr("underlying_denom", underlying_denom),
+    ]))
+}
+
+/// Mint receipt tokens 1:1 for the attached underlying denom
+pub fn exec_deposit(
+    ctx: Context,
+    recipient: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let recipient = ctx.recipient_or_sender(recipient)?;
+    let Context { deps, env, info } = ctx;
+    let wrapped_asset = load_wrapped_asset(deps.storage)?;
+    let amount = must_pay(&info, &wrapped_asset.underlying_denom)?;
+
+    WRAPPED_ASSET_STATS.update(deps.storage, |mut stats| -> Result<_, ContractError> {
+        stats.amount_minted = stats
+            .amount_minted
+            .checked_add(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        Ok(stats)
+    })?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "deposit"),
+            attr("recipient", recipient.to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessage(build_backed_mint_submsg(
+            deps.storage,
+            &env,
+            &recipient,
+            amount,
+        )?))
+}
+
+/// Burn attached receipt tokens, returning the underlying denom 1:1. Receipts
+/// are fungible with tokens minted by other modes, so withdrawals are capped
+/// by the receipts outstanding in this mode.
+pub fn exec_withdraw(ctx: Context) -> Result<Response, ContractError> {
+    let Context { deps, env, info } = ctx;
+    let wrapped_asset = load_wrapped_asset(deps.storage)?;
+    let amount = must_pay(&info, &FULL_DENOM.load(deps.storage)?)?;
+    let mut stats: WrappedAssetStats = WRAPPED_ASSET_STATS.load(deps.storage)?;
+
+    if amount > stats.outstanding() {
+        return Err(ContractError::ValidationError {
+            reason: format!(
+                "amount exceeds the {} receipts outstanding",
+                stats.outstanding()
+            ),
+        });
+    }
+
+    stats.amount_burned = stats
+        .amount_burned
+        .checked_add(amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    WRAPPED_ASSET_STATS.save(deps.storage, &stats)?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            attr("action", "withdraw"),
+            attr("recipient", info.sender.to_string()),
+            attr("amount", amount.to_string()),
+        ])
+        .add_submessages(build_burn_submsgs(
+            deps.storage,
+            &env,
+            &info.sender,
+            amount,
+        )?)
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin::new(amount.u128(), wrapped_asset.underlying_denom)],
+        }))
+}
+
+fn load_wrapped_asset(storage: &dyn Storage) -> Result<WrappedAsset, ContractError> {
+    WRAPPED_ASSET
+        .may_load(storage)?
+        .ok_or_else(|| ContractError::ValidationError {
+            reason: "wrapped asset not configured".to_owned(),
+        })
+}
@@ -0,0 +1,198 @@
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, Binary, DepsMut, Env, IbcBasicResponse, IbcChannel,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder,
+    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, Storage,
+};
+
+use crate::{
+    error::ContractError,
+    execute::bridge::{receive_bridge_packet, refund_bridge_packet},
+    msg::{BridgeAck, BridgePacket},
+    state::{
+        models::BridgeChannel,
+        storage::{BRIDGE_CHANNELS, BRIDGE_PEERS},
+    },
+};
+
+pub const BRIDGE_VERSION: &str = "cw-tokenfactory-bridge-1";
+pub const BRIDGE_ORDER: IbcOrder = IbcOrder::Unordered;
+
+#[entry_point]
+pub fn ibc_channel_open(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    validate_channel(deps.storage, msg.channel(), msg.counterparty_version())?;
+    Ok(())
+}
+
+#[entry_point]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+    validate_channel(deps.storage, channel, msg.counterparty_version())?;
+
+    // Totals carry over if a closed channel with the same ID is reopened
+    let existing = BRIDGE_CHANNELS.may_load(deps.storage, &channel.endpoint.channel_id)?;
+
+    BRIDGE_CHANNELS.save(
+        deps.storage,
+        &channel.endpoint.channel_id,
+        &BridgeChannel {
+            channel_id: channel.endpoint.channel_id.to_owned(),
+            connection_id: channel.connection_id.to_owned(),
+            counterparty_port_id: channel.counterparty_endpoint.port_id.to_owned(),
+            counterparty_channel_id: channel.counterparty_endpoint.channel_id.to_owned(),
+            amount_sent: existing.as_ref().map(|c| c.amount_sent).unwrap_or_default(),
+            amount_received: existing
+                .as_ref()
+                .map(|c| c.amount_received)
+                .unwrap_or_default(),
+            is_open: true,
+        },
+    )?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_connect")
+        .add_attribute("channel_id", channel.endpoint.channel_id.to_owned()))
+}
+
+#[entry_point]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel_id = &msg.channel().endpoint.channel_id;
+
+    if let Some(mut channel) = BRIDGE_CHANNELS.may_load(deps.storage, channel_id)? {
+        channel.is_open = false;
+        BRIDGE_CHANNELS.save(deps.storage, channel_id, &channel)?;
+    }
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_close")
+        .add_attribute("channel_id", channel_id.to_owned()))
+}
+
+/// Mint tokens burned on the counterparty chain. Failures are returned as
+/// error acks so that the sender re-mints.
+#[entry_point]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let channel_id = msg.packet.dest.channel_id.to_owned();
+    let result = from_json::<BridgePacket>(&msg.packet.data)
+        .map_err(ContractError::from)
+        .and_then(|packet| {
+            let recipient = deps.api.addr_validate(&packet.recipient)?;
+            let submsg =
+                receive_bridge_packet(deps.storage, &env, &recipient, &channel_id, packet.amount)?;
+            Ok((packet, submsg))
+        });
+
+    Ok(match result {
+        Ok((packet, submsg)) => IbcReceiveResponse::new()
+            .set_ack(to_json_binary(&BridgeAck::Result(Binary::from(b"1")))?)
+            .add_attribute("action", "bridge_receive")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("recipient", packet.recipient)
+            .add_attribute("amount", packet.amount.to_string())
+            .add_submessage(submsg),
+        Err(e) => IbcReceiveResponse::new()
+            .set_ack(to_json_binary(&BridgeAck::Error(e.to_string()))?)
+            .add_attribute("action", "bridge_receive")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("error", e.to_string()),
+    })
+}
+
+#[entry_point]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let packet: BridgePacket = from_json(&msg.original_packet.data)?;
+    let channel_id = msg.original_packet.src.channel_id;
+
+    match from_json::<BridgeAck>(&msg.acknowledgement.data)? {
+        BridgeAck::Result(_) => Ok(IbcBasicResponse::new()
+            .add_attribute("action", "bridge_ack")
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("amount", packet.amount.to_string())),
+        BridgeAck::Error(e) => Ok(IbcBasicResponse::new()
+            .add_attribute("action", "bridge_ack_error")
+            .add_attribute("channel_id", channel_id.to_owned())
+            .add_attribute("error", e)
+            .add_attribute("refund", packet.amount.to_string())
+            .add_submessage(refund_bridge_packet(
+                deps.storage,
+                &env,
+                &channel_id,
+                &packet,
+            )?)),
+    }
+}
+
+#[entry_point]
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let packet: BridgePacket = from_json(&msg.packet.data)?;
+    let channel_id = msg.packet.src.channel_id;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "bridge_timeout")
+        .add_attribute("channel_id", channel_id.to_owned())
+        .add_attribute("refund", packet.amount.to_string())
+        .add_submessage(refund_bridge_packet(
+            deps.storage,
+            &env,
+            &channel_id,
+            &packet,
+        )?))
+}
+
+/// Only pair with allowed peers, using this bridge's version and ordering
+fn validate_channel(
+    storage: &dyn Storage,
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.order != BRIDGE_ORDER {
+        return Err(ContractError::ValidationError {
+            reason: "bridge channels must be unordered".to_owned(),
+        });
+    }
+
+    if channel.version != BRIDGE_VERSION
+        || counterparty_version
+            .map(|v| v != BRIDGE_VERSION)
+            .unwrap_or(false)
+    {
+        return Err(ContractError::ValidationError {
+            reason: format!("bridge channel version must be {}", BRIDGE_VERSION),
+        });
+    }
+
+    let peer = (
+        channel.connection_id.as_str(),
+        channel.counterparty_endpoint.port_id.as_str(),
+    );
+    if !BRIDGE_PEERS.has(storage, peer) {
+        return Err(ContractError::NotAuthorized {
+            reason: format!("{} on {} is not an allowed bridge peer", peer.1, peer.0),
+        });
+    }
+
+    Ok(())
+}
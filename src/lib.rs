@@ -3,6 +3,8 @@ pub mod contract;
 pub mod error;
 #[cfg(not(feature = "library"))]
 pub mod execute;
+#[cfg(not(feature = "library"))]
+pub mod ibc;
 pub mod msg;
 #[cfg(not(feature = "library"))]
 pub mod query;
@@ -1,8 +1,17 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128, Uint256};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal256, Int256, Timestamp, Uint128, Uint256};
+use cw20::Cw20ReceiveMsg;
 
 use crate::{
-    state::models::Config,
+    state::models::{
+        AirdropStage, Auction, AuctionBid, AuctionState, Automation, Basket, BasketComponent,
+        BasketRebalance, BasketState, BridgeChannel, Cdp, CdpPosition, CdpState, Config,
+        Contribution, Curve, CurveState, CurveType, Cw20Migration, Cw20Wrapper, EmissionSchedule,
+        EmissionStream, Holder, Hook, LiquidationEvent, MigrationProgress, MintFee, PaymentStream,
+        Raise, RaisePricing, RaiseState, RemoteTransfer, Sale, SaleStats, ScheduledMint,
+        StakingConfig, StakingState, StakingValidator, UnbondingClaim, VoucherSigner, WrappedAsset,
+        WrappedAssetStats,
+    },
     tf::{
         cosmos::common::{DenomUnit, Metadata},
         tokenfactory::TokenFactoryType,
@@ -15,6 +24,7 @@ pub struct InstantiateMsg {
     pub factory: Option<TokenFactoryType>,
     pub initial_balances: Option<Vec<MintParams>>,
     pub metadata: NewDenomMetadata,
+    pub mint_fee: Option<MintFee>,
 }
 
 #[cw_serde]
@@ -68,12 +78,361 @@ pub enum ExecuteMsg {
     SetDenomMetadata { metadata: NewDenomMetadata },
     SetDenomAdmin { address: Addr },
     RemoveDenomAdmin {},
+    SetMintFee { mint_fee: Option<MintFee> },
+    /// Cap the total amount minted through this contract, or remove the cap
+    SetMaxSupply { max_supply: Option<Uint128> },
+    SetCw20Wrapper { address: Addr },
+    Receive(Cw20ReceiveMsg),
+    Unwrap {},
+    StartCw20Migration { token: Addr },
+    UploadMigrationSnapshot { balances: Vec<MintParams> },
+    SnapshotCw20Balances { limit: Option<u32> },
+    FinalizeCw20Migration {},
+    /// Claim the sender's snapshot balance, burning the equivalent legacy
+    /// cw20 through an allowance granted to this contract. Holders can also
+    /// claim by sending the legacy cw20 to this contract.
+    ClaimMigration {},
+    /// Claim for the next page of holders that have granted this contract an
+    /// allowance covering their snapshot balance. Others are skipped.
+    PushMigration { limit: Option<u32> },
+    RegisterAirdrop {
+        merkle_root: String,
+        total_amount: Uint128,
+        start: Option<Timestamp>,
+        expiration: Option<Timestamp>,
+    },
+    ClaimAirdrop { stage: u32, amount: Uint128, proof: Vec<String> },
+    ClawbackUnclaimed { stage: u32, recipient: Option<Addr> },
+    ContinueDistribution { limit: Option<u32> },
+    /// Mint and queue another page of initial balances, for lists too long
+    /// to pass to instantiate
+    AppendInitialBalances { balances: Vec<MintParams> },
+    ConfigureSale {
+        price_denom: String,
+        price_per_token: Uint128,
+        start: Option<Timestamp>,
+        end: Option<Timestamp>,
+        max_per_address: Option<Uint128>,
+        hard_cap: Option<Uint128>,
+        treasury: Option<Addr>,
+    },
+    /// `recipient` receives the minted tokens and any refund, in place of the
+    /// sender. Set it when buying through a contract or ibc-hooks.
+    Buy { recipient: Option<Addr> },
+    ConfigureCurve {
+        reserve_denom: String,
+        curve_type: CurveType,
+        entry_fee_bps: Option<u16>,
+        exit_fee_bps: Option<u16>,
+        treasury: Option<Addr>,
+    },
+    BuyFromCurve { recipient: Option<Addr> },
+    SellToCurve {},
+    ConfigureAuction {
+        reserve_denom: String,
+        start_price: Uint128,
+        floor_price: Uint128,
+        start: Timestamp,
+        end: Timestamp,
+        step_seconds: Option<u64>,
+        supply: Uint128,
+        treasury: Option<Addr>,
+    },
+    /// `recipient` owns the bid, receiving its tokens at settlement and any
+    /// refund, in place of the sender
+    PlaceBid { recipient: Option<Addr> },
+    SettleAuction { limit: Option<u32> },
+    /// Refund the sender's bid in full if the auction is still unsettled
+    /// some time after it ended
+    ReclaimBid {},
+    ConfigureRaise {
+        denom: String,
+        soft_cap: Uint128,
+        hard_cap: Option<Uint128>,
+        start: Timestamp,
+        end: Timestamp,
+        pricing: RaisePricing,
+        treasury: Option<Addr>,
+    },
+    /// `recipient` owns the contribution, claiming its tokens or refunding
+    /// it in place of the sender
+    Contribute { recipient: Option<Addr> },
+    /// Claim the sender's allotment from the raise of `round`, defaulting to
+    /// the latest
+    Claim { round: Option<u64> },
+    /// Refund the sender's contribution to the raise of `round`, defaulting
+    /// to the latest
+    Refund { round: Option<u64> },
+    WithdrawRaiseProceeds { round: Option<u64> },
+    ConfigureWrappedAsset { underlying_denom: String },
+    Deposit { recipient: Option<Addr> },
+    Withdraw {},
+    ConfigureStaking {
+        bond_denom: Option<String>,
+        validators: Vec<StakingValidator>,
+        unbonding_period: u64,
+    },
+    Stake { recipient: Option<Addr> },
+    Compound {},
+    Unstake {},
+    ClaimUnbonded {},
+    ConfigureBasket { components: Vec<BasketComponent>, timelock_seconds: u64 },
+    MintBasket { units: Uint128, recipient: Option<Addr> },
+    RedeemBasket {},
+    ProposeBasketRebalance { components: Vec<BasketComponent> },
+    ExecuteBasketRebalance {},
+    CancelBasketRebalance {},
+    ConfigureCdp {
+        oracle: Addr,
+        collateral_denoms: Vec<String>,
+        collateral_ratio: Decimal256,
+        liquidation_ratio: Decimal256,
+        liquidation_bonus: Decimal256,
+    },
+    DepositCollateral {},
+    WithdrawCollateral { collateral: Vec<Coin> },
+    Borrow { amount: Uint128 },
+    Repay {},
+    Liquidate { owner: Addr },
+    AddEmission {
+        recipient: Addr,
+        start: Option<Timestamp>,
+        schedule: EmissionSchedule,
+        total: Option<Uint128>,
+    },
+    RemoveEmission { id: u64 },
+    /// Mint what up to `limit` streams have accrued, continuing from the
+    /// last stream visited by the previous call
+    Emit { limit: Option<u32> },
+    SetAutomation { enabled: bool, max_actions: Option<u32> },
+    RegisterClock {},
+    /// Run a batch of scheduled work. Only callable by this contract.
+    RunAutomation {},
+    CreateStream { recipient: Addr, rate_per_second: Uint128, start: Timestamp, end: Timestamp },
+    WithdrawStream { id: u64 },
+    CancelStream { id: u64 },
+    ScheduleMint { recipient: Addr, amount: Uint128, not_before: Timestamp },
+    ExecuteDueMints { limit: Option<u32> },
+    CancelScheduledMint { id: u64 },
+    SetVoucherSigner { signer: Option<VoucherSigner> },
+    RedeemVoucher {
+        recipient: Addr,
+        amount: Uint128,
+        nonce: u64,
+        expires: Timestamp,
+        signature: Binary,
+    },
+    MintAndCall { contract: Addr, amount: Uint128, msg: Binary },
+    MintToRemote {
+        channel_id: String,
+        remote_recipient: String,
+        amount: Uint128,
+        /// Seconds until the transfer times out
+        timeout: Option<u64>,
+        /// ICS-20 memo, as a JSON object, for packet-forward or ibc-hooks
+        memo: Option<String>,
+        /// Receives the tokens if the transfer fails. Burned if not set.
+        refund_address: Option<Addr>,
+    },
+    SetBridgePeer { connection_id: String, port_id: String, is_allowed: bool },
+    /// Burn attached tokens and mint them on the chain at the other end of
+    /// a bridge channel
+    BridgeTransfer { channel_id: String, remote_recipient: String, timeout: Option<u64> },
+    /// Notify `contract` of mints and burns. Unless `is_blocking`, a failing
+    /// hook is ignored.
+    AddHook { contract: Addr, is_blocking: Option<bool> },
+    RemoveHook { contract: Addr },
+    /// Register this contract as the denom's BeforeSend hook, mirroring
+    /// holder balances from then on
+    EnableHolderTracking {},
+    /// Reset the mirrored balance of `address` to its bank balance
+    ReconcileHolder { address: Addr },
+}
+
+impl ExecuteMsg {
+    /// Messages that may be sent by accounts other than the manager. These
+    /// perform their own authorization.
+    pub fn is_permissionless(&self) -> bool {
+        matches!(
+            self,
+            Self::Receive(_)
+                | Self::Unwrap {}
+                | Self::ClaimMigration {}
+                | Self::PushMigration { .. }
+                | Self::ClaimAirdrop { .. }
+                | Self::ContinueDistribution { .. }
+                | Self::Buy { .. }
+                | Self::BuyFromCurve { .. }
+                | Self::SellToCurve {}
+                | Self::PlaceBid { .. }
+                | Self::SettleAuction { .. }
+                | Self::ReclaimBid {}
+                | Self::Contribute { .. }
+                | Self::Claim { .. }
+                | Self::Refund { .. }
+                | Self::WithdrawRaiseProceeds { .. }
+                | Self::Deposit { .. }
+                | Self::Withdraw {}
+                | Self::Stake { .. }
+                | Self::Compound {}
+                | Self::Unstake {}
+                | Self::ClaimUnbonded {}
+                | Self::MintBasket { .. }
+                | Self::RedeemBasket {}
+                | Self::ExecuteBasketRebalance {}
+                | Self::DepositCollateral {}
+                | Self::WithdrawCollateral { .. }
+                | Self::Borrow { .. }
+                | Self::Repay {}
+                | Self::Liquidate { .. }
+                | Self::Emit { .. }
+                | Self::RunAutomation {}
+                | Self::WithdrawStream { .. }
+                | Self::ExecuteDueMints { .. }
+                | Self::RedeemVoucher { .. }
+                | Self::BridgeTransfer { .. }
+                | Self::ReconcileHolder { .. }
+        )
+    }
+}
+
+/// Msg embedded in a cw20 `Send` to this contract
+#[cw_serde]
+pub enum Cw20HookMsg {
+    Wrap { recipient: Option<Addr> },
+}
+
+/// Payload signed by the voucher signer, serialized as JSON with fields in
+/// this order. Secp256k1 signatures are over the sha256 hash of the JSON;
+/// ed25519 signatures are over the JSON bytes.
+#[cw_serde]
+pub struct VoucherPayload {
+    pub chain_id: String,
+    pub contract: Addr,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub nonce: u64,
+    pub expires: Timestamp,
+}
+
+/// Packet sent between bridge instances. The sender has already burned
+/// `amount`, which the receiver mints to `recipient`.
+#[cw_serde]
+pub struct BridgePacket {
+    pub sender: String,
+    pub recipient: String,
+    pub amount: Uint128,
+}
+
+/// Bridge packet acknowledgement, in the same shape as ICS-20 acks
+#[cw_serde]
+pub enum BridgeAck {
+    Result(Binary),
+    Error(String),
+}
+
+/// Execute msg sent to each hook contract after tokens are minted or burned
+#[cw_serde]
+pub enum TokenFactoryHookMsg {
+    /// `recipient` is a remote address for tokens minted to another chain
+    Minted { recipient: String, amount: Uint128, denom: String },
+    /// `owner` held the tokens before they were burned
+    Burned { owner: String, amount: Uint128, denom: String },
+}
+
+/// Query interface expected of the CDP price oracle
+#[cw_serde]
+pub enum OracleQueryMsg {
+    /// Value of one base unit of `denom`, in base units of this contract's
+    /// tokenfactory denom
+    Price { denom: String },
+}
+
+#[cw_serde]
+pub struct OraclePriceResponse {
+    pub price: Decimal256,
 }
 
 #[cw_serde]
 pub enum QueryMsg {
     Config {},
     Info {},
+    Cw20Wrapper {},
+    /// cw20-compatible token info for the tokenfactory denom
+    TokenInfo {},
+    /// cw20-compatible bank balance of the tokenfactory denom
+    Balance { address: String },
+    /// cw20-compatible minter info, reporting the manager as minter
+    Minter {},
+    /// cw20-compatible marketing info derived from denom metadata
+    MarketingInfo {},
+    MigrationStatus {},
+    MigrationBalance { address: Addr },
+    AirdropStage { stage: u32 },
+    AirdropClaimed { stage: u32, address: Addr },
+    DistributionStatus {},
+    SaleStatus {},
+    SalePurchases { address: Addr },
+    CurvePrice { amount: Uint128 },
+    CurveState {},
+    AuctionStatus {},
+    AuctionPrice {},
+    AuctionBid { address: Addr },
+    /// Raise queries default to the latest round
+    RaiseStatus { round: Option<u64> },
+    RaiseContribution { address: Addr, round: Option<u64> },
+    RaiseContributions { round: Option<u64>, start_after: Option<Addr>, limit: Option<u32> },
+    Backing {},
+    StakingState {},
+    UnbondingClaims { address: Addr },
+    BasketComposition {},
+    BasketNav {},
+    CdpConfig {},
+    CdpPosition { address: Addr },
+    CdpPositions { start_after: Option<Addr>, limit: Option<u32> },
+    CdpLiquidations { start_after: Option<u64>, limit: Option<u32> },
+    PendingEmissions {},
+    Automation {},
+    PaymentStream { id: u64 },
+    PaymentStreams { recipient: Addr, start_after: Option<u64>, limit: Option<u32> },
+    /// Scheduled mints in order of `not_before`
+    ScheduledMints { start_after: Option<u64>, limit: Option<u32> },
+    VoucherSigner {},
+    VoucherNonceUsed { nonce: u64 },
+    /// Unacknowledged remote transfers, paginated by (channel ID, sequence)
+    RemoteTransfers { start_after: Option<(String, u64)>, limit: Option<u32> },
+    BridgeChannel { channel_id: String },
+    BridgeChannels { start_after: Option<String>, limit: Option<u32> },
+    Hooks {},
+    HolderCount {},
+    /// Holders, paginated by address
+    Holders { start_after: Option<Addr>, limit: Option<u32> },
+    /// Holders by descending balance
+    TopHolders { limit: Option<u32> },
+}
+
+/// End-block messages delivered by chain modules
+#[cw_serde]
+pub enum SudoMsg {
+    /// Sent every block by Juno's clock module
+    ClockEndBlock {},
+    /// Sent by a Neutron cron schedule configured to target this contract
+    CronTick {},
+    /// Sent by ibc-hooks once an IBC transfer sent by this contract is acked
+    /// or times out
+    IbcLifecycleComplete(IbcLifecycleComplete),
+    /// Sent by tokenfactory before each transfer of the denom, once this
+    /// contract is its BeforeSend hook. Errors are ignored.
+    TrackBeforeSend { from: String, to: String, amount: Coin },
+    /// Sent alongside `TrackBeforeSend`. An error here would block the
+    /// transfer.
+    BlockBeforeSend { from: String, to: String, amount: Coin },
+}
+
+#[cw_serde]
+pub enum IbcLifecycleComplete {
+    IbcAck { channel: String, sequence: u64, ack: String, success: bool },
+    IbcTimeout { channel: String, sequence: u64 },
 }
 
 #[cw_serde]
@@ -86,6 +445,8 @@ pub struct ConfigResponse(pub Config);
 pub struct ContractStats {
     pub amount_burned: Uint256,
     pub amount_minted: Uint256,
+    /// Portion of `amount_minted` paid out as mint fees
+    pub amount_fees_minted: Uint256,
 }
 
 #[cw_serde]
@@ -94,3 +455,263 @@ pub struct InfoResponse {
     pub metadata: Metadata,
     pub stats: ContractStats,
 }
+
+#[cw_serde]
+pub struct Cw20WrapperResponse {
+    pub wrapper: Option<Cw20Wrapper>,
+    pub amount_wrapped: Uint128,
+}
+
+#[cw_serde]
+pub struct MigrationStatusResponse {
+    pub migration: Option<Cw20Migration>,
+    pub progress: MigrationProgress,
+    pub unclaimed_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct MigrationBalanceResponse {
+    pub unclaimed: Uint128,
+    pub claimed: Uint128,
+}
+
+#[cw_serde]
+pub struct AirdropStageResponse {
+    pub stage: u32,
+    pub latest_stage: u32,
+    pub airdrop: AirdropStage,
+    pub is_active: bool,
+}
+
+#[cw_serde]
+pub struct AirdropClaimedResponse {
+    pub is_claimed: bool,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct DistributionStatusResponse {
+    pub remaining_count: u32,
+    pub remaining_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct SaleStatusResponse {
+    pub sale: Option<Sale>,
+    pub stats: SaleStats,
+    pub is_active: bool,
+}
+
+#[cw_serde]
+pub struct SalePurchasesResponse {
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct CurvePriceResponse {
+    /// Current price, in reserve units, of one whole token
+    pub spot_price: Decimal256,
+    /// Reserve needed to buy the requested amount, including entry fee
+    pub cost: Uint128,
+    pub entry_fee: Uint128,
+}
+
+#[cw_serde]
+pub struct CurveStateResponse {
+    pub curve: Option<Curve>,
+    pub state: CurveState,
+    pub spot_price: Option<Decimal256>,
+}
+
+#[cw_serde]
+pub struct AuctionStatusResponse {
+    pub auction: Option<Auction>,
+    pub state: AuctionState,
+    pub is_closed: bool,
+}
+
+#[cw_serde]
+pub struct AuctionPriceResponse {
+    pub current_price: Uint128,
+    /// Price all bidders would pay if the auction closed now
+    pub clearing_price: Uint128,
+    pub is_closed: bool,
+}
+
+#[cw_serde]
+pub struct AuctionBidResponse {
+    pub bid: Option<AuctionBid>,
+    /// Tokens the bid would receive at the current clearing price
+    pub estimated_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct RaiseStatusResponse {
+    pub raise: Option<Raise>,
+    pub state: RaiseState,
+    pub is_open: bool,
+    /// Whether the soft cap was met, once the raise has ended
+    pub is_successful: Option<bool>,
+}
+
+#[cw_serde]
+pub struct RaiseContributionResponse {
+    pub address: Addr,
+    pub contribution: Contribution,
+    /// Tokens owed if the raise succeeds, based on current totals
+    pub allotment: Uint128,
+}
+
+#[cw_serde]
+pub struct RaiseContributionsResponse {
+    pub contributions: Vec<RaiseContributionResponse>,
+}
+
+#[cw_serde]
+pub struct BackingResponse {
+    pub wrapped_asset: Option<WrappedAsset>,
+    pub stats: WrappedAssetStats,
+    /// Receipts minted in this mode and not yet withdrawn, each owed one
+    /// unit of the underlying
+    pub outstanding: Uint128,
+    /// Underlying denom held by the contract
+    pub underlying_balance: Uint128,
+    /// Whether the underlying balance covers the outstanding receipts
+    pub is_backed: bool,
+}
+
+#[cw_serde]
+pub struct StakingStateResponse {
+    pub config: Option<StakingConfig>,
+    pub state: StakingState,
+    /// Delegation rewards accrued and not yet withdrawn
+    pub pending_rewards: Uint128,
+    /// Backing tokens per receipt token, including pending rewards
+    pub exchange_rate: Decimal256,
+}
+
+#[cw_serde]
+pub struct UnbondingClaimsResponse {
+    pub claims: Vec<UnbondingClaim>,
+    /// Sum of claims that have finished unbonding
+    pub claimable: Uint128,
+}
+
+#[cw_serde]
+pub struct BasketCompositionResponse {
+    pub basket: Option<Basket>,
+    pub state: BasketState,
+    pub pending_rebalance: Option<BasketRebalance>,
+}
+
+#[cw_serde]
+pub struct BasketNavResponse {
+    /// Components deposited by basket mints and not yet redeemed
+    pub holdings: Vec<Coin>,
+    /// Holdings redeemable for one whole basket token
+    pub nav_per_unit: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct CdpConfigResponse {
+    pub cdp: Option<Cdp>,
+    pub state: CdpState,
+}
+
+#[cw_serde]
+pub struct CdpPositionResponse {
+    pub address: Addr,
+    pub position: CdpPosition,
+    /// Collateral value, in base units of the tokenfactory denom
+    pub collateral_value: Uint128,
+    pub ratio: Option<Decimal256>,
+    pub is_liquidatable: bool,
+}
+
+#[cw_serde]
+pub struct CdpPositionsResponse {
+    pub positions: Vec<CdpPositionResponse>,
+}
+
+#[cw_serde]
+pub struct CdpLiquidationsResponse {
+    pub liquidations: Vec<LiquidationEvent>,
+}
+
+#[cw_serde]
+pub struct PendingEmission {
+    pub stream: EmissionStream,
+    /// Accrued but not yet emitted
+    pub pending: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingEmissionsResponse {
+    pub emissions: Vec<PendingEmission>,
+    pub total_pending: Uint128,
+}
+
+#[cw_serde]
+pub struct AutomationResponse {
+    pub automation: Option<Automation>,
+}
+
+#[cw_serde]
+pub struct PaymentStreamResponse {
+    pub stream: PaymentStream,
+    /// Accrued but not yet withdrawn
+    pub withdrawable: Uint128,
+}
+
+#[cw_serde]
+pub struct PaymentStreamsResponse {
+    pub streams: Vec<PaymentStreamResponse>,
+}
+
+#[cw_serde]
+pub struct ScheduledMintsResponse {
+    pub mints: Vec<ScheduledMint>,
+}
+
+#[cw_serde]
+pub struct VoucherSignerResponse {
+    pub signer: Option<VoucherSigner>,
+}
+
+#[cw_serde]
+pub struct VoucherNonceUsedResponse {
+    pub is_used: bool,
+}
+
+#[cw_serde]
+pub struct RemoteTransfersResponse {
+    pub transfers: Vec<RemoteTransfer>,
+}
+
+#[cw_serde]
+pub struct BridgeChannelResponse {
+    pub channel: BridgeChannel,
+    /// Net supply moved to the counterparty chain. Negative if more has
+    /// arrived from it than has been sent.
+    pub net_outflow: Int256,
+}
+
+#[cw_serde]
+pub struct BridgeChannelsResponse {
+    pub channels: Vec<BridgeChannelResponse>,
+}
+
+#[cw_serde]
+pub struct HooksResponse {
+    pub hooks: Vec<Hook>,
+}
+
+#[cw_serde]
+pub struct HolderCountResponse {
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct HoldersResponse {
+    pub holders: Vec<Holder>,
+}
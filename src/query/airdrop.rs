@@ -0,0 +1,36 @@
+use cosmwasm_std::Addr;
+
+use crate::{
+    error::ContractError,
+    msg::{AirdropClaimedResponse, AirdropStageResponse},
+    state::storage::{AIRDROP_CLAIMS, AIRDROP_LATEST_STAGE, AIRDROP_STAGES},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_airdrop_stage(
+    ctx: ReadonlyContext,
+    stage: u32,
+) -> Result<AirdropStageResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let airdrop = AIRDROP_STAGES.load(deps.storage, stage)?;
+    Ok(AirdropStageResponse {
+        stage,
+        latest_stage: AIRDROP_LATEST_STAGE.load(deps.storage)?,
+        is_active: airdrop.is_active(env.block.time),
+        airdrop,
+    })
+}
+
+pub fn query_airdrop_claimed(
+    ctx: ReadonlyContext,
+    stage: u32,
+    address: Addr,
+) -> Result<AirdropClaimedResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let amount = AIRDROP_CLAIMS.may_load(deps.storage, (stage, &address))?;
+    Ok(AirdropClaimedResponse {
+        is_claimed: amount.is_some(),
+        amount: amount.unwrap_or_default(),
+    })
+}
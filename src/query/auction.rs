@@ -0,0 +1,64 @@
+use cosmwasm_std::{Addr, Uint128};
+
+use crate::{
+    error::ContractError,
+    execute::auction::load_auction,
+    msg::{AuctionBidResponse, AuctionPriceResponse, AuctionStatusResponse},
+    state::storage::{AUCTION, AUCTION_BIDS, AUCTION_STATE},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_auction_status(ctx: ReadonlyContext) -> Result<AuctionStatusResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let auction = AUCTION.may_load(deps.storage)?;
+    let state = AUCTION_STATE.may_load(deps.storage)?.unwrap_or_default();
+    Ok(AuctionStatusResponse {
+        is_closed: auction
+            .as_ref()
+            .map(|a| a.is_closed(env.block.time, state.total_committed))
+            .unwrap_or(true),
+        auction,
+        state,
+    })
+}
+
+pub fn query_auction_price(ctx: ReadonlyContext) -> Result<AuctionPriceResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let auction = load_auction(deps.storage)?;
+    let state = AUCTION_STATE.load(deps.storage)?;
+    Ok(AuctionPriceResponse {
+        current_price: auction.price(env.block.time),
+        clearing_price: state
+            .clearing_price
+            .unwrap_or_else(|| auction.clearing_price(state.total_committed)),
+        is_closed: auction.is_closed(env.block.time, state.total_committed),
+    })
+}
+
+pub fn query_auction_bid(
+    ctx: ReadonlyContext,
+    address: Addr,
+) -> Result<AuctionBidResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let auction = AUCTION.may_load(deps.storage)?;
+    let bid = match &auction {
+        Some(auction) => AUCTION_BIDS.may_load(deps.storage, (auction.round, &address))?,
+        None => None,
+    };
+    let estimated_amount = match (&bid, auction) {
+        (Some(bid), Some(auction)) => {
+            let state = AUCTION_STATE.load(deps.storage)?;
+            let clearing_price = state
+                .clearing_price
+                .unwrap_or_else(|| auction.clearing_price(state.total_committed));
+            bid.committed
+                .multiply_ratio(auction.token_unit, clearing_price)
+        },
+        _ => Uint128::zero(),
+    };
+    Ok(AuctionBidResponse {
+        bid,
+        estimated_amount,
+    })
+}
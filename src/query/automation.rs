@@ -0,0 +1,10 @@
+use crate::{error::ContractError, msg::AutomationResponse, state::storage::AUTOMATION};
+
+use super::ReadonlyContext;
+
+pub fn query_automation(ctx: ReadonlyContext) -> Result<AutomationResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(AutomationResponse {
+        automation: AUTOMATION.may_load(deps.storage)?,
+    })
+}
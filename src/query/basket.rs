@@ -0,0 +1,50 @@
+use cosmwasm_std::Coin;
+
+use crate::{
+    error::ContractError,
+    msg::{BasketCompositionResponse, BasketNavResponse},
+    state::storage::{BASKET, BASKET_REBALANCE, BASKET_STATE},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_basket_composition(
+    ctx: ReadonlyContext
+) -> Result<BasketCompositionResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(BasketCompositionResponse {
+        basket: BASKET.may_load(deps.storage)?,
+        state: BASKET_STATE.may_load(deps.storage)?.unwrap_or_default(),
+        pending_rebalance: BASKET_REBALANCE.may_load(deps.storage)?,
+    })
+}
+
+/// Net asset value of the basket, in component holdings per whole token
+pub fn query_basket_nav(ctx: ReadonlyContext) -> Result<BasketNavResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let state = BASKET_STATE.may_load(deps.storage)?.unwrap_or_default();
+    let token_unit = BASKET
+        .may_load(deps.storage)?
+        .map(|b| b.token_unit)
+        .unwrap_or_default();
+
+    let nav_per_unit = if state.supply.is_zero() {
+        vec![]
+    } else {
+        state
+            .holdings
+            .iter()
+            .map(|h| {
+                Coin::new(
+                    h.amount.multiply_ratio(token_unit, state.supply).u128(),
+                    h.denom.to_owned(),
+                )
+            })
+            .collect()
+    };
+
+    Ok(BasketNavResponse {
+        holdings: state.holdings,
+        nav_per_unit,
+    })
+}
@@ -0,0 +1,50 @@
+use cosmwasm_std::{Int256, Order, StdResult};
+use cw_storage_plus::Bound;
+
+use crate::{
+    error::ContractError,
+    msg::{BridgeChannelResponse, BridgeChannelsResponse},
+    state::{models::BridgeChannel, storage::BRIDGE_CHANNELS, DEFAULT_LIMIT, MAX_LIMIT},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_bridge_channel(
+    ctx: ReadonlyContext,
+    channel_id: String,
+) -> Result<BridgeChannelResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(to_response(
+        BRIDGE_CHANNELS.load(deps.storage, &channel_id)?,
+    ))
+}
+
+pub fn query_bridge_channels(
+    ctx: ReadonlyContext,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<BridgeChannelsResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let channels = BRIDGE_CHANNELS
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| r.map(|(_, channel)| to_response(channel)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(BridgeChannelsResponse { channels })
+}
+
+/// Net tokens bridged out over the channel, negative if more came in
+fn to_response(channel: BridgeChannel) -> BridgeChannelResponse {
+    BridgeChannelResponse {
+        net_outflow: Int256::from(channel.amount_sent.u128())
+            - Int256::from(channel.amount_received.u128()),
+        channel,
+    }
+}
@@ -0,0 +1,97 @@
+use cosmwasm_std::{Addr, Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+
+use crate::{
+    error::ContractError,
+    execute::cdp::{collateral_value, is_liquidatable},
+    msg::{CdpConfigResponse, CdpLiquidationsResponse, CdpPositionResponse, CdpPositionsResponse},
+    state::{
+        models::CdpPosition,
+        storage::{CDP, CDP_LIQUIDATIONS, CDP_POSITIONS, CDP_STATE},
+        DEFAULT_LIMIT, MAX_LIMIT,
+    },
+};
+
+use super::ReadonlyContext;
+
+pub fn query_cdp_config(ctx: ReadonlyContext) -> Result<CdpConfigResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(CdpConfigResponse {
+        cdp: CDP.may_load(deps.storage)?,
+        state: CDP_STATE.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+pub fn query_cdp_position(
+    ctx: ReadonlyContext,
+    address: Addr,
+) -> Result<CdpPositionResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let position = CDP_POSITIONS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    to_response(deps, address, position)
+}
+
+pub fn query_cdp_positions(
+    ctx: ReadonlyContext,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<CdpPositionsResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let positions = CDP_POSITIONS
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .map(|(address, position)| to_response(deps, address, position))
+        .collect::<Result<Vec<_>, ContractError>>()?;
+    Ok(CdpPositionsResponse { positions })
+}
+
+pub fn query_cdp_liquidations(
+    ctx: ReadonlyContext,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<CdpLiquidationsResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let liquidations = CDP_LIQUIDATIONS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| r.map(|(_, event)| event))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(CdpLiquidationsResponse { liquidations })
+}
+
+fn to_response(
+    deps: Deps,
+    address: Addr,
+    position: CdpPosition,
+) -> Result<CdpPositionResponse, ContractError> {
+    let (collateral_value, is_liquidatable) = match CDP.may_load(deps.storage)? {
+        Some(cdp) => {
+            let value = collateral_value(deps.querier, &cdp, &position.collateral)?;
+            (value, is_liquidatable(&cdp, &position, value))
+        },
+        None => (Default::default(), false),
+    };
+    Ok(CdpPositionResponse {
+        ratio: position.ratio(collateral_value),
+        address,
+        position,
+        collateral_value,
+        is_liquidatable,
+    })
+}
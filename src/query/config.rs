@@ -1,7 +1,10 @@
 use crate::{
     error::ContractError,
     msg::ConfigResponse,
-    state::{models::Config, storage::MANAGER},
+    state::{
+        models::Config,
+        storage::{MANAGER, MAX_SUPPLY, MINT_FEE},
+    },
 };
 
 use super::ReadonlyContext;
@@ -10,5 +13,7 @@ pub fn query_config(ctx: ReadonlyContext) -> Result<ConfigResponse, ContractErro
     let ReadonlyContext { deps, .. } = ctx;
     Ok(ConfigResponse(Config {
         manager: MANAGER.load(deps.storage)?,
+        mint_fee: MINT_FEE.may_load(deps.storage)?,
+        max_supply: MAX_SUPPLY.may_load(deps.storage)?,
     }))
 }
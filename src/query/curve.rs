@@ -0,0 +1,48 @@
+use cosmwasm_std::{StdError, Uint128};
+
+use crate::{
+    error::ContractError,
+    execute::curve::{load_curve, MAX_FEE_BPS},
+    msg::{CurvePriceResponse, CurveStateResponse},
+    state::storage::{CURVE, CURVE_STATE},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_curve_price(
+    ctx: ReadonlyContext,
+    amount: Uint128,
+) -> Result<CurvePriceResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let curve = load_curve(deps.storage)?;
+    let state = CURVE_STATE.load(deps.storage)?;
+    let reserve_in = curve.cost_to_buy(state.supply, amount)?;
+
+    // Gross up the reserve needed by the entry fee, rounding up
+    let denominator = Uint128::from(MAX_FEE_BPS - curve.entry_fee_bps);
+    let cost = reserve_in
+        .checked_mul(Uint128::from(MAX_FEE_BPS))
+        .and_then(|n| n.checked_add(denominator - Uint128::one()))
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?
+        / denominator;
+
+    Ok(CurvePriceResponse {
+        spot_price: curve.price(state.supply)?,
+        entry_fee: cost - reserve_in,
+        cost,
+    })
+}
+
+pub fn query_curve_state(ctx: ReadonlyContext) -> Result<CurveStateResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let curve = CURVE.may_load(deps.storage)?;
+    let state = CURVE_STATE.may_load(deps.storage)?.unwrap_or_default();
+    Ok(CurveStateResponse {
+        spot_price: match &curve {
+            Some(curve) => Some(curve.price(state.supply)?),
+            None => None,
+        },
+        curve,
+        state,
+    })
+}
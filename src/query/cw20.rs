@@ -0,0 +1,54 @@
+use crate::{
+    error::ContractError,
+    state::storage::{DENOM_METADATA, FULL_DENOM, MANAGER, MAX_SUPPLY},
+};
+use cosmwasm_std::StdError;
+use cw20::{BalanceResponse, MarketingInfoResponse, MinterResponse, TokenInfoResponse};
+
+use super::ReadonlyContext;
+
+pub fn query_token_info(ctx: ReadonlyContext) -> Result<TokenInfoResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let denom = FULL_DENOM.load(deps.storage)?;
+    let metadata = DENOM_METADATA.load(deps.storage)?;
+    let supply = deps.querier.query_supply(denom)?;
+    Ok(TokenInfoResponse {
+        name: metadata.name,
+        symbol: metadata.symbol,
+        decimals: u8::try_from(metadata.decimals)
+            .map_err(|e| ContractError::Std(StdError::generic_err(e.to_string())))?,
+        total_supply: supply.amount,
+    })
+}
+
+pub fn query_balance(
+    ctx: ReadonlyContext,
+    address: String,
+) -> Result<BalanceResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let address = deps.api.addr_validate(&address)?;
+    let denom = FULL_DENOM.load(deps.storage)?;
+    Ok(BalanceResponse {
+        balance: deps.querier.query_balance(address, denom)?.amount,
+    })
+}
+
+/// The manager mints, up to the max supply if one is set
+pub fn query_minter(ctx: ReadonlyContext) -> Result<MinterResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(MinterResponse {
+        minter: MANAGER.load(deps.storage)?.to_string(),
+        cap: MAX_SUPPLY.may_load(deps.storage)?,
+    })
+}
+
+pub fn query_marketing_info(ctx: ReadonlyContext) -> Result<MarketingInfoResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let metadata = DENOM_METADATA.load(deps.storage)?;
+    Ok(MarketingInfoResponse {
+        project: metadata.uri,
+        description: metadata.description,
+        logo: None,
+        marketing: Some(MANAGER.load(deps.storage)?),
+    })
+}
@@ -0,0 +1,17 @@
+use crate::{
+    error::ContractError,
+    msg::Cw20WrapperResponse,
+    state::storage::{CW20_AMOUNT_WRAPPED, CW20_WRAPPER},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_cw20_wrapper(ctx: ReadonlyContext) -> Result<Cw20WrapperResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(Cw20WrapperResponse {
+        wrapper: CW20_WRAPPER.may_load(deps.storage)?,
+        amount_wrapped: CW20_AMOUNT_WRAPPED
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    })
+}
@@ -0,0 +1,19 @@
+use crate::{
+    error::ContractError,
+    msg::DistributionStatusResponse,
+    state::storage::{DISTRIBUTION_AMOUNT_REMAINING, INITIAL_BALANCES},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_distribution_status(
+    ctx: ReadonlyContext
+) -> Result<DistributionStatusResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(DistributionStatusResponse {
+        remaining_count: INITIAL_BALANCES.len(deps.storage)?,
+        remaining_amount: DISTRIBUTION_AMOUNT_REMAINING
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    })
+}
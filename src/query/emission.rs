@@ -0,0 +1,31 @@
+use cosmwasm_std::{Order, StdResult, Uint128};
+
+use crate::{
+    error::ContractError,
+    msg::{PendingEmission, PendingEmissionsResponse},
+    state::storage::EMISSION_STREAMS,
+};
+
+use super::ReadonlyContext;
+
+pub fn query_pending_emissions(
+    ctx: ReadonlyContext
+) -> Result<PendingEmissionsResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let mut total_pending = Uint128::zero();
+    let emissions = EMISSION_STREAMS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|r| r.map(|(_, stream)| stream))
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .map(|stream| {
+            let pending = stream.pending(env.block.time)?;
+            total_pending += pending;
+            Ok(PendingEmission { stream, pending })
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+    Ok(PendingEmissionsResponse {
+        emissions,
+        total_pending,
+    })
+}
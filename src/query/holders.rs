@@ -0,0 +1,58 @@
+use cosmwasm_std::{Addr, Order, StdResult};
+use cw_storage_plus::Bound;
+
+use crate::{
+    error::ContractError,
+    msg::{HolderCountResponse, HoldersResponse},
+    state::{
+        storage::{holders, HOLDER_COUNT},
+        DEFAULT_LIMIT, MAX_LIMIT,
+    },
+};
+
+use super::ReadonlyContext;
+
+pub fn query_holder_count(ctx: ReadonlyContext) -> Result<HolderCountResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(HolderCountResponse {
+        count: HOLDER_COUNT.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+pub fn query_holders(
+    ctx: ReadonlyContext,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<HoldersResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let holders = holders()
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| r.map(|(_, holder)| holder))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(HoldersResponse { holders })
+}
+
+pub fn query_top_holders(
+    ctx: ReadonlyContext,
+    limit: Option<u32>,
+) -> Result<HoldersResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let holders = holders()
+        .idx
+        .balance
+        .range(deps.storage, None, None, Order::Descending)
+        .take(limit)
+        .map(|r| r.map(|(_, holder)| holder))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(HoldersResponse { holders })
+}
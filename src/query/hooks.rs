@@ -0,0 +1,15 @@
+use cosmwasm_std::{Order, StdResult};
+
+use crate::{error::ContractError, msg::HooksResponse, state::storage::HOOKS};
+
+use super::ReadonlyContext;
+
+pub fn query_hooks(ctx: ReadonlyContext) -> Result<HooksResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let hooks = HOOKS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|r| r.map(|(_, hook)| hook))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(HooksResponse { hooks })
+}
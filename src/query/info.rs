@@ -1,7 +1,9 @@
 use crate::{
     error::ContractError,
     msg::{ContractStats, InfoResponse},
-    state::storage::{AMOUNT_BURNED, AMOUNT_MINTED, DENOM_METADATA, FULL_DENOM},
+    state::storage::{
+        AMOUNT_BURNED, AMOUNT_FEES_MINTED, AMOUNT_MINTED, DENOM_METADATA, FULL_DENOM,
+    },
 };
 
 use super::ReadonlyContext;
@@ -14,6 +16,9 @@ pub fn query_info(ctx: ReadonlyContext) -> Result<InfoResponse, ContractError> {
         stats: ContractStats {
             amount_burned: AMOUNT_BURNED.load(deps.storage)?,
             amount_minted: AMOUNT_MINTED.load(deps.storage)?,
+            amount_fees_minted: AMOUNT_FEES_MINTED
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
         },
         denom,
     })
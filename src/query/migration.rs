@@ -0,0 +1,38 @@
+use cosmwasm_std::Addr;
+
+use crate::{
+    error::ContractError,
+    msg::{MigrationBalanceResponse, MigrationStatusResponse},
+    state::storage::{CW20_MIGRATION, MIGRATION_CLAIMED, MIGRATION_PROGRESS, MIGRATION_UNCLAIMED},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_migration_status(
+    ctx: ReadonlyContext
+) -> Result<MigrationStatusResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let progress = MIGRATION_PROGRESS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    Ok(MigrationStatusResponse {
+        migration: CW20_MIGRATION.may_load(deps.storage)?,
+        unclaimed_amount: progress.total_amount - progress.claimed_amount,
+        progress,
+    })
+}
+
+pub fn query_migration_balance(
+    ctx: ReadonlyContext,
+    address: Addr,
+) -> Result<MigrationBalanceResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(MigrationBalanceResponse {
+        unclaimed: MIGRATION_UNCLAIMED
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default(),
+        claimed: MIGRATION_CLAIMED
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default(),
+    })
+}
@@ -1,5 +1,27 @@
+pub mod airdrop;
+pub mod auction;
+pub mod automation;
+pub mod basket;
+pub mod bridge;
+pub mod cdp;
 pub mod config;
+pub mod curve;
+pub mod cw20;
+pub mod cw20_wrapper;
+pub mod distribution;
+pub mod emission;
+pub mod holders;
+pub mod hooks;
 pub mod info;
+pub mod migration;
+pub mod payment_stream;
+pub mod raise;
+pub mod remote_mint;
+pub mod sale;
+pub mod scheduled_mint;
+pub mod staking;
+pub mod voucher;
+pub mod wrapped_asset;
 
 pub use config::query_config;
 use cosmwasm_std::{Deps, Env};
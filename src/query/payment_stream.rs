@@ -0,0 +1,55 @@
+use cosmwasm_std::{Addr, Order, StdResult, Timestamp};
+use cw_storage_plus::Bound;
+
+use crate::{
+    error::ContractError,
+    msg::{PaymentStreamResponse, PaymentStreamsResponse},
+    state::{models::PaymentStream, storage::payment_streams, DEFAULT_LIMIT, MAX_LIMIT},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_payment_stream(
+    ctx: ReadonlyContext,
+    id: u64,
+) -> Result<PaymentStreamResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let stream = payment_streams().load(deps.storage, id)?;
+    to_response(stream, env.block.time)
+}
+
+pub fn query_payment_streams(
+    ctx: ReadonlyContext,
+    recipient: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PaymentStreamsResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let streams = payment_streams()
+        .idx
+        .recipient
+        .prefix(recipient)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .map(|(_, stream)| to_response(stream, env.block.time))
+        .collect::<Result<Vec<_>, ContractError>>()?;
+    Ok(PaymentStreamsResponse { streams })
+}
+
+fn to_response(
+    stream: PaymentStream,
+    time: Timestamp,
+) -> Result<PaymentStreamResponse, ContractError> {
+    Ok(PaymentStreamResponse {
+        withdrawable: stream.withdrawable(time)?,
+        stream,
+    })
+}
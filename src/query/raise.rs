@@ -0,0 +1,109 @@
+use cosmwasm_std::{Addr, Order, StdResult, Uint128};
+use cw_storage_plus::Bound;
+
+use crate::{
+    error::ContractError,
+    execute::raise::load_raise,
+    msg::{RaiseContributionResponse, RaiseContributionsResponse, RaiseStatusResponse},
+    state::{
+        models::{Contribution, Raise, RaiseState},
+        storage::RAISE_CONTRIBUTIONS,
+        DEFAULT_LIMIT, MAX_LIMIT,
+    },
+};
+
+use super::ReadonlyContext;
+
+pub fn query_raise_status(
+    ctx: ReadonlyContext,
+    round: Option<u64>,
+) -> Result<RaiseStatusResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let (raise, state) = match load_raise(deps.storage, round) {
+        Ok((raise, state)) => (Some(raise), state),
+        Err(_) => (None, RaiseState::default()),
+    };
+    Ok(RaiseStatusResponse {
+        is_open: raise
+            .as_ref()
+            .map(|r| r.is_open(env.block.time))
+            .unwrap_or(false),
+        is_successful: raise
+            .as_ref()
+            .filter(|r| r.is_ended(env.block.time))
+            .map(|r| state.total_contributed >= r.soft_cap),
+        raise,
+        state,
+    })
+}
+
+pub fn query_raise_contribution(
+    ctx: ReadonlyContext,
+    address: Addr,
+    round: Option<u64>,
+) -> Result<RaiseContributionResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(match load_raise(deps.storage, round) {
+        Ok((raise, state)) => {
+            let contribution = RAISE_CONTRIBUTIONS
+                .may_load(deps.storage, (raise.round, &address))?
+                .unwrap_or_default();
+            to_response(&raise, &state, address, contribution)
+        },
+        Err(_) => RaiseContributionResponse {
+            address,
+            contribution: Contribution::default(),
+            allotment: Uint128::zero(),
+        },
+    })
+}
+
+pub fn query_raise_contributions(
+    ctx: ReadonlyContext,
+    round: Option<u64>,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<RaiseContributionsResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let (raise, state) = match load_raise(deps.storage, round) {
+        Ok(loaded) => loaded,
+        Err(_) => {
+            return Ok(RaiseContributionsResponse {
+                contributions: vec![],
+            })
+        },
+    };
+    let contributions = RAISE_CONTRIBUTIONS
+        .prefix(raise.round)
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| {
+            r.map(|(address, contribution)| to_response(&raise, &state, address, contribution))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(RaiseContributionsResponse { contributions })
+}
+
+fn to_response(
+    raise: &Raise,
+    state: &RaiseState,
+    address: Addr,
+    contribution: Contribution,
+) -> RaiseContributionResponse {
+    let allotment = if state.total_contributed.is_zero() {
+        Uint128::zero()
+    } else {
+        raise.allotment(contribution.amount, state.total_contributed)
+    };
+    RaiseContributionResponse {
+        address,
+        contribution,
+        allotment,
+    }
+}
@@ -0,0 +1,32 @@
+use cosmwasm_std::{Order, StdResult};
+use cw_storage_plus::Bound;
+
+use crate::{
+    error::ContractError,
+    msg::RemoteTransfersResponse,
+    state::{storage::REMOTE_TRANSFERS, DEFAULT_LIMIT, MAX_LIMIT},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_remote_transfers(
+    ctx: ReadonlyContext,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> Result<RemoteTransfersResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let transfers = REMOTE_TRANSFERS
+        .range(
+            deps.storage,
+            start_after
+                .as_ref()
+                .map(|(channel, sequence)| Bound::exclusive((channel.as_str(), *sequence))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|r| r.map(|(_, transfer)| transfer))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(RemoteTransfersResponse { transfers })
+}
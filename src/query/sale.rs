@@ -0,0 +1,36 @@
+use cosmwasm_std::{Addr, Uint128};
+
+use crate::{
+    error::ContractError,
+    msg::{SalePurchasesResponse, SaleStatusResponse},
+    state::storage::{SALE, SALE_PURCHASES, SALE_STATS},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_sale_status(ctx: ReadonlyContext) -> Result<SaleStatusResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let sale = SALE.may_load(deps.storage)?;
+    Ok(SaleStatusResponse {
+        is_active: sale
+            .as_ref()
+            .map(|s| s.is_active(env.block.time))
+            .unwrap_or(false),
+        stats: SALE_STATS.may_load(deps.storage)?.unwrap_or_default(),
+        sale,
+    })
+}
+
+pub fn query_sale_purchases(
+    ctx: ReadonlyContext,
+    address: Addr,
+) -> Result<SalePurchasesResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let amount = match SALE.may_load(deps.storage)? {
+        Some(sale) => SALE_PURCHASES
+            .may_load(deps.storage, (sale.round, &address))?
+            .unwrap_or_default(),
+        None => Uint128::zero(),
+    };
+    Ok(SalePurchasesResponse { amount })
+}
@@ -0,0 +1,36 @@
+use cosmwasm_std::{Order, StdResult};
+use cw_storage_plus::Bound;
+
+use crate::{
+    error::ContractError,
+    msg::ScheduledMintsResponse,
+    state::{storage::scheduled_mints, DEFAULT_LIMIT, MAX_LIMIT},
+};
+
+use super::ReadonlyContext;
+
+/// Scheduled mints in order of `not_before`, paginated by the ID of the last
+/// mint in the previous page
+pub fn query_scheduled_mints(
+    ctx: ReadonlyContext,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<ScheduledMintsResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let min = match start_after {
+        Some(id) => {
+            let m = scheduled_mints().load(deps.storage, id)?;
+            Some(Bound::exclusive((m.not_before.seconds(), id)))
+        },
+        None => None,
+    };
+    let mints = scheduled_mints()
+        .idx
+        .not_before
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit)
+        .map(|r| r.map(|(_, m)| m))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(ScheduledMintsResponse { mints })
+}
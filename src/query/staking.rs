@@ -0,0 +1,50 @@
+use cosmwasm_std::{Addr, StdError, Uint128};
+
+use crate::{
+    error::ContractError,
+    execute::staking::build_withdraw_reward_msgs,
+    msg::{StakingStateResponse, UnbondingClaimsResponse},
+    state::storage::{STAKING_CONFIG, STAKING_STATE, UNBONDING_CLAIMS},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_staking_state(ctx: ReadonlyContext) -> Result<StakingStateResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let config = STAKING_CONFIG.may_load(deps.storage)?;
+    let state = STAKING_STATE.may_load(deps.storage)?.unwrap_or_default();
+    let pending_rewards = match &config {
+        Some(config) => build_withdraw_reward_msgs(deps.querier, &env, &config.bond_denom)?.2,
+        None => Uint128::zero(),
+    };
+
+    // Pending rewards back the receipts as soon as they are withdrawn
+    let mut with_rewards = state.to_owned();
+    with_rewards.idle_rewards = state
+        .idle_rewards
+        .checked_add(pending_rewards)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+    Ok(StakingStateResponse {
+        config,
+        exchange_rate: with_rewards.exchange_rate()?,
+        pending_rewards,
+        state,
+    })
+}
+
+pub fn query_unbonding_claims(
+    ctx: ReadonlyContext,
+    address: Addr,
+) -> Result<UnbondingClaimsResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let claims = UNBONDING_CLAIMS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    let claimable: Uint128 = claims
+        .iter()
+        .filter(|c| c.release_at <= env.block.time)
+        .map(|c| c.amount)
+        .sum();
+    Ok(UnbondingClaimsResponse { claims, claimable })
+}
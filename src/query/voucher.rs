@@ -0,0 +1,24 @@
+use crate::{
+    error::ContractError,
+    msg::{VoucherNonceUsedResponse, VoucherSignerResponse},
+    state::storage::{VOUCHER_NONCES, VOUCHER_SIGNER},
+};
+
+use super::ReadonlyContext;
+
+pub fn query_voucher_signer(ctx: ReadonlyContext) -> Result<VoucherSignerResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(VoucherSignerResponse {
+        signer: VOUCHER_SIGNER.may_load(deps.storage)?,
+    })
+}
+
+pub fn query_voucher_nonce_used(
+    ctx: ReadonlyContext,
+    nonce: u64,
+) -> Result<VoucherNonceUsedResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(VoucherNonceUsedResponse {
+        is_used: VOUCHER_NONCES.has(deps.storage, nonce),
+    })
+}
@@ -0,0 +1,33 @@
+use cosmwasm_std::Uint128;
+
+use crate::{
+    error::ContractError,
+    msg::BackingResponse,
+    state::storage::{WRAPPED_ASSET, WRAPPED_ASSET_STATS},
+};
+
+use super::ReadonlyContext;
+
+/// Underlying held by the contract, checked against outstanding receipts
+pub fn query_backing(ctx: ReadonlyContext) -> Result<BackingResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+    let wrapped_asset = WRAPPED_ASSET.may_load(deps.storage)?;
+    let stats = WRAPPED_ASSET_STATS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let underlying_balance = match &wrapped_asset {
+        Some(wrapped_asset) => {
+            deps.querier
+                .query_balance(&env.contract.address, &wrapped_asset.underlying_denom)?
+                .amount
+        },
+        None => Uint128::zero(),
+    };
+    Ok(BackingResponse {
+        wrapped_asset,
+        outstanding: stats.outstanding(),
+        is_backed: underlying_balance >= stats.outstanding(),
+        underlying_balance,
+        stats,
+    })
+}
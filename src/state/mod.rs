@@ -1,25 +1,65 @@
 pub mod models;
 pub mod storage;
 
+use std::collections::BTreeSet;
+
 use cosmwasm_std::{
-    BankMsg, Coin, DepsMut, Reply, Response, StdError, SubMsg, SubMsgResult, Uint128, Uint256,
-    Uint64,
+    Api, BankMsg, Coin, DepsMut, Reply, Response, StdError, Storage, SubMsg, SubMsgResult, Uint128,
+    Uint256, Uint64,
 };
 use storage::{
-    AMOUNT_BURNED, AMOUNT_MINTED, DENOM_METADATA, FACTORY, FULL_DENOM, INITIAL_BALANCES, MANAGER,
-    MINT_REPLY_ID_COUNTER,
+    AMOUNT_BURNED, AMOUNT_FEES_MINTED, AMOUNT_MINTED, DENOM_METADATA,
+    DISTRIBUTION_AMOUNT_REMAINING, FACTORY, FULL_DENOM, INITIAL_BALANCES, INITIAL_MINT_PENDING,
+    MANAGER, MINT_FEE, MINT_REPLY_ID_COUNTER,
 };
 
 use crate::{
     error::ContractError,
-    execute::Context,
+    execute::{
+        hooks::{build_mint_hook_submsgs, hook_count},
+        Context,
+    },
     msg::{InstantiateMsg, MintParams},
     tf::tokenfactory::TokenFactoryType,
 };
 
 pub const INITIAL_BALANCES_REPLY_ID: u64 = 0;
+pub const AUTOMATION_REPLY_ID: u64 = 1;
+pub const REMOTE_TRANSFER_REPLY_ID: u64 = 2;
+pub const HOOK_REPLY_ID: u64 = 3;
 pub const INITIAL_MINT_REPLY_ID: u64 = 1_000_000u64;
 
+/// Default and max page sizes for paginated queries and batched executions
+pub const DEFAULT_LIMIT: u32 = 30;
+pub const MAX_LIMIT: u32 = 100;
+
+/// Max number of msgs sent in the instantiate reply to distribute initial
+/// balances and notify hooks. Any remaining are sent through
+/// `ContinueDistribution`.
+pub const INITIAL_DISTRIBUTION_LIMIT: u32 = 50;
+
+/// Max number of initial balances accepted by instantiate or one
+/// `AppendInitialBalances`, keeping each within block gas limits
+pub const MAX_INITIAL_BALANCES: usize = 100;
+
+/// Time after an auction ends from which bids it has not settled can be
+/// reclaimed by their owners
+pub const AUCTION_RECLAIM_DELAY_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Default timeout for IBC transfers of minted tokens
+pub const DEFAULT_IBC_TIMEOUT_SECONDS: u64 = 600;
+
+/// Max number of mint and burn hooks, bounding the gas they add to each
+/// mint and burn
+pub const MAX_HOOKS: usize = 10;
+
+/// Gas available to each non-blocking hook, so a failing or expensive hook
+/// cannot use up the gas of the mint or burn that triggered it
+pub const HOOK_GAS_LIMIT: u64 = 200_000;
+
+/// Default max scheduled items processed per block by automation
+pub const DEFAULT_AUTOMATION_MAX_ACTIONS: u32 = 10;
+
 /// Top-level initialization of contract state
 pub fn init(
     ctx: Context,
@@ -55,17 +95,14 @@ pub fn init(
         .add_attribute("action", "instantiate")
         .add_submessages(denom_msgs);
 
-    if let Some(initial_balances) = msg.initial_balances {
-        let mut total_initial_mint_amount = Uint128::zero();
-        for x in initial_balances.iter() {
-            deps.api.addr_validate(x.address.as_str())?;
-
-            total_initial_mint_amount = total_initial_mint_amount
-                .checked_add(x.amount.into())
-                .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    if let Some(mint_fee) = &msg.mint_fee {
+        mint_fee.validate(deps.api)?;
+        MINT_FEE.save(deps.storage, mint_fee)?;
+    }
 
-            INITIAL_BALANCES.push_back(deps.storage, x)?;
-        }
+    if let Some(initial_balances) = msg.initial_balances {
+        let total_initial_mint_amount =
+            queue_initial_balances(deps.storage, deps.api, &initial_balances)?;
 
         resp = resp.add_submessage(SubMsg::reply_always(
             factory.mint(
@@ -77,50 +114,162 @@ pub fn init(
         ));
     }
 
+    if DISTRIBUTION_AMOUNT_REMAINING
+        .may_load(deps.storage)?
+        .is_none()
+    {
+        DISTRIBUTION_AMOUNT_REMAINING.save(deps.storage, &Uint128::zero())?;
+    }
     FULL_DENOM.save(deps.storage, &full_denom)?;
     FACTORY.save(deps.storage, &factory)?;
     MINT_REPLY_ID_COUNTER.save(deps.storage, &Uint64::from(INITIAL_MINT_REPLY_ID))?;
     AMOUNT_MINTED.save(deps.storage, &Uint256::zero())?;
     AMOUNT_BURNED.save(deps.storage, &Uint256::zero())?;
+
+    if AMOUNT_FEES_MINTED.may_load(deps.storage)?.is_none() {
+        AMOUNT_FEES_MINTED.save(deps.storage, &Uint256::zero())?;
+    }
     DENOM_METADATA.save(deps.storage, &msg.metadata)?;
 
     Ok(resp)
 }
 
+/// Validate and queue a page of initial balances for distribution, returning
+/// the amount to mint for them, including the mint fee. Amounts must be
+/// non-zero and addresses unique within the page.
+pub fn queue_initial_balances(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    balances: &[MintParams],
+) -> Result<Uint128, ContractError> {
+    if balances.len() > MAX_INITIAL_BALANCES {
+        return Err(ContractError::ValidationError {
+            reason: format!(
+                "at most {} initial balances can be added at once",
+                MAX_INITIAL_BALANCES
+            ),
+        });
+    }
+
+    let mut total_amount = Uint128::zero();
+    let mut addresses = BTreeSet::new();
+
+    for x in balances.iter() {
+        api.addr_validate(x.address.as_str())?;
+
+        // A zero send fails, which would jam the queue behind it
+        if x.amount.is_zero() {
+            return Err(ContractError::ValidationError {
+                reason: format!("initial balance for {} must be non-zero", x.address),
+            });
+        }
+        if !addresses.insert(x.address.as_str()) {
+            return Err(ContractError::ValidationError {
+                reason: format!("duplicate initial balance for {}", x.address),
+            });
+        }
+
+        total_amount = total_amount
+            .checked_add(x.amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+        INITIAL_BALANCES.push_back(storage, x)?;
+    }
+
+    // The fee on the initial supply is queued for distribution ahead of
+    // the initial balances, so it goes out in the first batch
+    if let Some(fee) = MINT_FEE
+        .may_load(storage)?
+        .and_then(|mint_fee| mint_fee.fee_for(total_amount))
+    {
+        total_amount = total_amount
+            .checked_add(fee.amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+        let amount_fees_minted = AMOUNT_FEES_MINTED
+            .may_load(storage)?
+            .unwrap_or_default()
+            .checked_add(fee.amount.into())
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        AMOUNT_FEES_MINTED.save(storage, &amount_fees_minted)?;
+
+        INITIAL_BALANCES.push_front(
+            storage,
+            &MintParams {
+                address: fee.recipient,
+                amount: fee.amount,
+            },
+        )?;
+    }
+
+    let remaining = DISTRIBUTION_AMOUNT_REMAINING
+        .may_load(storage)?
+        .unwrap_or_default()
+        .checked_add(total_amount)
+        .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+    DISTRIBUTION_AMOUNT_REMAINING.save(storage, &remaining)?;
+    INITIAL_MINT_PENDING.save(storage, &total_amount)?;
+
+    Ok(total_amount)
+}
+
 pub fn transfer_initial_balances(
     deps: DepsMut,
     reply: Reply,
 ) -> Result<Response, ContractError> {
-    let mut send_msgs: Vec<SubMsg> = Vec::with_capacity(4);
     match reply.result {
         SubMsgResult::Ok(_) => {
-            let denom = FULL_DENOM.load(deps.storage)?;
-            let n = INITIAL_BALANCES.len(deps.storage)?;
-            let mut total_amount = Uint256::zero();
-
-            for _ in 0..n {
-                let MintParams { amount, address } =
-                    INITIAL_BALANCES.pop_front(deps.storage)?.unwrap();
-
-                total_amount = total_amount
-                    .checked_add(amount.into())
-                    .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
-
-                send_msgs.push(SubMsg::new(BankMsg::Send {
-                    to_address: address.to_string(),
-                    amount: vec![Coin::new(amount.into(), denom.to_owned())],
-                }))
-            }
+            // Each page of initial balances is minted at once, even though
+            // it may take multiple transactions to send it all out.
+            let total_amount = INITIAL_MINT_PENDING.load(deps.storage)?;
+            INITIAL_MINT_PENDING.remove(deps.storage);
 
             AMOUNT_MINTED.update(deps.storage, |n| -> Result<_, ContractError> {
-                Ok(n.checked_add(total_amount)
-                    .map_err(|e| ContractError::Std(StdError::overflow(e)))?)
+                n.checked_add(total_amount.into())
+                    .map_err(|e| ContractError::Std(StdError::overflow(e)))
             })?;
+
+            Ok(Response::new().add_submessages(send_initial_balances(
+                deps.storage,
+                INITIAL_DISTRIBUTION_LIMIT,
+            )?))
         },
-        SubMsgResult::Err(e) => {
-            return Err(ContractError::Std(StdError::generic_err(e.to_string())))
-        },
+        SubMsgResult::Err(e) => Err(ContractError::Std(StdError::generic_err(e.to_string()))),
     }
+}
+
+/// Send queued initial balances out of the initial mint in up to `limit`
+/// msgs. Each balance takes one bank send plus one msg per hook.
+pub fn send_initial_balances(
+    storage: &mut dyn Storage,
+    limit: u32,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let denom = FULL_DENOM.load(storage)?;
+    let msgs_per_balance = 1 + hook_count(storage) as u32;
+    let n = INITIAL_BALANCES
+        .len(storage)?
+        .min((limit / msgs_per_balance).max(1));
+    let mut send_msgs: Vec<SubMsg> = Vec::with_capacity(n as usize);
+    let mut total_amount = Uint128::zero();
+
+    for _ in 0..n {
+        let MintParams { amount, address } = INITIAL_BALANCES.pop_front(storage)?.unwrap();
+
+        total_amount = total_amount
+            .checked_add(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+
+        send_msgs.push(SubMsg::new(BankMsg::Send {
+            to_address: address.to_string(),
+            amount: vec![Coin::new(amount.into(), denom.to_owned())],
+        }));
+        send_msgs.extend(build_mint_hook_submsgs(storage, address.as_str(), amount)?);
+    }
+
+    DISTRIBUTION_AMOUNT_REMAINING.update(storage, |n| -> Result<_, ContractError> {
+        n.checked_sub(total_amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))
+    })?;
 
-    Ok(Response::new().add_submessages(send_msgs))
+    Ok(send_msgs)
 }
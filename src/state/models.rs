@@ -1,7 +1,1252 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    Addr, Api, Binary, Coin, Decimal256, OverflowError, StdError, Timestamp, Uint128, Uint256,
+};
+
+use crate::error::ContractError;
 
 #[cw_serde]
 pub struct Config {
     pub manager: Addr,
+    pub mint_fee: Option<MintFee>,
+    pub max_supply: Option<Uint128>,
+}
+
+/// Protocol fee minted on top of each issuance and sent to `recipient`
+#[cw_serde]
+pub struct MintFee {
+    pub bps: u16,
+    pub recipient: Addr,
+}
+
+impl MintFee {
+    pub fn validate(
+        &self,
+        api: &dyn Api,
+    ) -> Result<(), ContractError> {
+        api.addr_validate(self.recipient.as_str())?;
+        if self.bps >= 10_000 {
+            return Err(ContractError::ValidationError {
+                reason: "mint fee must be less than 10000 bps".to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Fee owed on a mint of the given amount, if non-zero
+    pub fn fee_for(
+        &self,
+        amount: Uint128,
+    ) -> Option<FeePayment> {
+        let fee = amount.multiply_ratio(self.bps, 10_000u128);
+        if fee.is_zero() {
+            None
+        } else {
+            Some(FeePayment {
+                recipient: self.recipient.to_owned(),
+                amount: fee,
+            })
+        }
+    }
+}
+
+#[cw_serde]
+pub struct FeePayment {
+    pub recipient: Addr,
+    pub amount: Uint128,
+}
+
+/// Where the coins of a pending mint go once minted
+#[cw_serde]
+pub enum MintDelivery {
+    Send {
+        recipient: Addr,
+    },
+    /// Execute a contract with the minted coins attached as funds
+    Execute {
+        contract: Addr,
+        msg: Binary,
+    },
+    /// ICS-20 transfer to a recipient on another chain
+    IbcTransfer {
+        channel_id: String,
+        remote_recipient: String,
+        timeout_seconds: u64,
+        memo: String,
+        refund_address: Option<Addr>,
+    },
+}
+
+impl MintDelivery {
+    /// Address the minted tokens are delivered to, which is on another chain
+    /// for IBC transfers
+    pub fn recipient(&self) -> String {
+        match self {
+            Self::Send { recipient } => recipient.to_string(),
+            Self::Execute { contract, .. } => contract.to_string(),
+            Self::IbcTransfer {
+                remote_recipient, ..
+            } => remote_recipient.to_owned(),
+        }
+    }
+}
+
+/// A mint awaiting its factory reply, after which the minted coins are
+/// delivered and the fee, if any, is sent to the fee recipient
+#[cw_serde]
+pub struct PendingMint {
+    pub amount: Uint128,
+    pub fee: Option<FeePayment>,
+    pub delivery: MintDelivery,
+}
+
+impl PendingMint {
+    /// Amount minted through the factory, including the fee
+    pub fn total(&self) -> Result<Uint128, ContractError> {
+        match &self.fee {
+            Some(fee) => self.amount.checked_add(fee.amount).map_err(overflow),
+            None => Ok(self.amount),
+        }
+    }
+}
+
+/// Converts amounts between a cw20 token and the tokenfactory denom, given the
+/// decimals of each at the time the conversion was configured.
+#[cw_serde]
+pub struct DecimalConversion {
+    pub cw20_decimals: u32,
+    pub native_decimals: u32,
+}
+
+impl DecimalConversion {
+    pub fn to_native(
+        &self,
+        cw20_amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        Self::rescale(cw20_amount, self.cw20_decimals, self.native_decimals, true)
+    }
+
+    /// Like `to_native` but rounds down instead of rejecting amounts that
+    /// can't be represented exactly.
+    pub fn to_native_floor(
+        &self,
+        cw20_amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        Self::rescale(cw20_amount, self.cw20_decimals, self.native_decimals, false)
+    }
+
+    pub fn to_cw20(
+        &self,
+        native_amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        Self::rescale(
+            native_amount,
+            self.native_decimals,
+            self.cw20_decimals,
+            true,
+        )
+    }
+
+    fn rescale(
+        amount: Uint128,
+        from_decimals: u32,
+        to_decimals: u32,
+        exact: bool,
+    ) -> Result<Uint128, ContractError> {
+        if to_decimals >= from_decimals {
+            let factor = Uint128::from(10u128)
+                .checked_pow(to_decimals - from_decimals)
+                .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+            amount
+                .checked_mul(factor)
+                .map_err(|e| ContractError::Std(StdError::overflow(e)))
+        } else {
+            let factor = Uint128::from(10u128)
+                .checked_pow(from_decimals - to_decimals)
+                .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+            // Reject amounts that would lose precision so that backing stays exact
+            if exact && !(amount % factor).is_zero() {
+                return Err(ContractError::ValidationError {
+                    reason: format!("amount {} must be a multiple of {}", amount, factor),
+                });
+            }
+            Ok(amount / factor)
+        }
+    }
+}
+
+/// A cw20 token that can be wrapped into, and unwrapped from, the
+/// tokenfactory denom.
+#[cw_serde]
+pub struct Cw20Wrapper {
+    pub address: Addr,
+    pub conversion: DecimalConversion,
+}
+
+/// One-way migration of holders from a legacy cw20 token
+#[cw_serde]
+pub struct Cw20Migration {
+    pub token: Addr,
+    pub conversion: DecimalConversion,
+    /// Last account read from the legacy token's `AllAccounts` query
+    pub snapshot_cursor: Option<String>,
+    /// Set once the legacy token's accounts have all been read
+    pub snapshot_complete: bool,
+    /// Once finalized, the snapshot is frozen and holders can claim
+    pub finalized: bool,
+    /// Last holder visited by `PushMigration`
+    pub push_cursor: Option<Addr>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct MigrationProgress {
+    pub holder_count: u32,
+    pub claimed_holder_count: u32,
+    pub total_amount: Uint128,
+    pub claimed_amount: Uint128,
+    pub legacy_amount_burned: Uint128,
+}
+
+/// A merkle-root airdrop stage, minted on demand as recipients claim
+#[cw_serde]
+pub struct AirdropStage {
+    /// Hex-encoded sha256 merkle root of `{address}{amount}` leaves
+    pub merkle_root: String,
+    pub total_amount: Uint128,
+    pub claimed_amount: Uint128,
+    pub start: Option<Timestamp>,
+    pub expiration: Option<Timestamp>,
+    /// Set once unclaimed tokens have been clawed back
+    pub closed: bool,
+}
+
+impl AirdropStage {
+    pub fn is_started(
+        &self,
+        time: Timestamp,
+    ) -> bool {
+        self.start.map(|t| time >= t).unwrap_or(true)
+    }
+
+    pub fn is_expired(
+        &self,
+        time: Timestamp,
+    ) -> bool {
+        self.expiration.map(|t| time >= t).unwrap_or(false)
+    }
+
+    pub fn is_active(
+        &self,
+        time: Timestamp,
+    ) -> bool {
+        !self.closed && self.is_started(time) && !self.is_expired(time)
+    }
+}
+
+/// Fixed-price sale of newly minted tokens
+#[cw_serde]
+pub struct Sale {
+    /// Incremented each time a sale is configured. Stats and purchases are
+    /// tracked per round.
+    pub round: u64,
+    pub price_denom: String,
+    /// Price, in `price_denom`, of one whole token (10^decimals base units)
+    pub price_per_token: Uint128,
+    /// Base units in one whole token at the time the sale was configured
+    pub token_unit: Uint128,
+    pub start: Option<Timestamp>,
+    pub end: Option<Timestamp>,
+    pub max_per_address: Option<Uint128>,
+    pub hard_cap: Option<Uint128>,
+    /// Recipient of sale proceeds
+    pub treasury: Addr,
+}
+
+impl Sale {
+    pub fn is_active(
+        &self,
+        time: Timestamp,
+    ) -> bool {
+        self.start.map(|t| time >= t).unwrap_or(true) && self.end.map(|t| time < t).unwrap_or(true)
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct SaleStats {
+    pub buyer_count: u32,
+    pub amount_sold: Uint128,
+    pub amount_raised: Uint128,
+}
+
+/// Shape of a bonding curve's price as a function of supply, where supply is
+/// measured in whole tokens and price in reserve base units per whole token.
+#[cw_serde]
+pub enum CurveType {
+    /// price = slope * supply + base_price
+    Linear {
+        slope: Decimal256,
+        base_price: Decimal256,
+    },
+    /// price = slope * sqrt(supply)
+    SquareRoot { slope: Decimal256 },
+    /// price = base_price * e^(growth * supply)
+    Exponential {
+        base_price: Decimal256,
+        growth: Decimal256,
+    },
+}
+
+impl CurveType {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        let is_valid = match self {
+            Self::Linear { slope, base_price } => !(slope.is_zero() && base_price.is_zero()),
+            Self::SquareRoot { slope } => !slope.is_zero(),
+            Self::Exponential { base_price, growth } => !base_price.is_zero() && !growth.is_zero(),
+        };
+        if !is_valid {
+            return Err(ContractError::ValidationError {
+                reason: "curve price must be non-zero".to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn price(
+        &self,
+        supply: Decimal256,
+    ) -> Result<Decimal256, ContractError> {
+        match self {
+            Self::Linear { slope, base_price } => slope
+                .checked_mul(supply)
+                .and_then(|x| x.checked_add(*base_price))
+                .map_err(overflow),
+            Self::SquareRoot { slope } => slope.checked_mul(supply.sqrt()).map_err(overflow),
+            Self::Exponential { base_price, growth } => base_price
+                .checked_mul(exp(growth.checked_mul(supply).map_err(overflow)?)?)
+                .map_err(overflow),
+        }
+    }
+
+    /// Reserve needed to back the given supply, i.e. the integral of price
+    /// from zero to `supply`.
+    pub fn reserve(
+        &self,
+        supply: Decimal256,
+    ) -> Result<Decimal256, ContractError> {
+        match self {
+            Self::Linear { slope, base_price } => {
+                let area = slope
+                    .checked_mul(supply.checked_pow(2).map_err(overflow)?)
+                    .map_err(overflow)?
+                    / Decimal256::from_ratio(2u128, 1u128);
+                area.checked_add(base_price.checked_mul(supply).map_err(overflow)?)
+                    .map_err(overflow)
+            },
+            Self::SquareRoot { slope } => Ok(slope
+                .checked_mul(supply)
+                .and_then(|x| x.checked_mul(supply.sqrt()))
+                .map_err(overflow)?
+                * Decimal256::from_ratio(2u128, 3u128)),
+            Self::Exponential { base_price, growth } => {
+                let growth_factor = exp(growth.checked_mul(supply).map_err(overflow)?)?;
+                Ok((*base_price / *growth)
+                    .checked_mul(growth_factor - Decimal256::one())
+                    .map_err(overflow)?)
+            },
+        }
+    }
+
+    /// Supply backed by the given reserve, i.e. the inverse of `reserve`, or
+    /// `None` if the curve has no closed-form inverse.
+    pub fn supply_for_reserve(
+        &self,
+        reserve: Decimal256,
+    ) -> Result<Option<Decimal256>, ContractError> {
+        match self {
+            Self::Linear { slope, base_price } => {
+                if slope.is_zero() {
+                    return Ok(Some(reserve / *base_price));
+                }
+                // Positive root of slope / 2 * s^2 + base_price * s - reserve
+                let discriminant = slope
+                    .checked_mul(reserve)
+                    .and_then(|x| x.checked_mul(Decimal256::from_ratio(2u128, 1u128)))
+                    .and_then(|x| x.checked_add(base_price.checked_pow(2)?))
+                    .map_err(overflow)?;
+                Ok(Some(
+                    discriminant.sqrt().saturating_sub(*base_price) / *slope,
+                ))
+            },
+            Self::SquareRoot { .. } => Ok(None),
+            Self::Exponential { base_price, growth } => {
+                let growth_factor = (reserve.checked_mul(*growth).map_err(overflow)? / *base_price)
+                    .checked_add(Decimal256::one())
+                    .map_err(overflow)?;
+                Ok(Some(ln(growth_factor)? / *growth))
+            },
+        }
+    }
+}
+
+/// Bonding curve that mints against, and redeems from, a reserve denom
+#[cw_serde]
+pub struct Curve {
+    pub reserve_denom: String,
+    pub curve_type: CurveType,
+    /// Base units in one whole token at the time the curve was configured
+    pub token_unit: Uint128,
+    pub entry_fee_bps: u16,
+    pub exit_fee_bps: u16,
+    /// Recipient of entry and exit fees
+    pub treasury: Addr,
+}
+
+impl Curve {
+    pub fn price(
+        &self,
+        supply: Uint128,
+    ) -> Result<Decimal256, ContractError> {
+        self.curve_type
+            .price(Decimal256::from_ratio(supply, self.token_unit))
+    }
+
+    pub fn reserve(
+        &self,
+        supply: Uint128,
+    ) -> Result<Decimal256, ContractError> {
+        self.curve_type
+            .reserve(Decimal256::from_ratio(supply, self.token_unit))
+    }
+
+    /// Reserve needed to mint `amount` more tokens, rounded up
+    pub fn cost_to_buy(
+        &self,
+        supply: Uint128,
+        amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        let new_supply = supply
+            .checked_add(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        let cost = self.reserve(new_supply)? - self.reserve(supply)?;
+        to_uint128(cost.to_uint_ceil())
+    }
+
+    /// Reserve released by burning `amount` tokens, rounded down
+    pub fn proceeds_from_sale(
+        &self,
+        supply: Uint128,
+        amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        let new_supply = supply
+            .checked_sub(amount)
+            .map_err(|e| ContractError::Std(StdError::overflow(e)))?;
+        let proceeds = self.reserve(supply)? - self.reserve(new_supply)?;
+        to_uint128(proceeds.to_uint_floor())
+    }
+
+    /// Max number of tokens that can be minted with `reserve_in`
+    pub fn amount_for_reserve(
+        &self,
+        supply: Uint128,
+        reserve_in: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        let budget = self
+            .reserve(supply)?
+            .checked_add(Decimal256::from_ratio(reserve_in, 1u128))
+            .map_err(overflow)?;
+
+        let fits = |amount: Uint128| -> bool {
+            supply
+                .checked_add(amount)
+                .ok()
+                .and_then(|new_supply| self.reserve(new_supply).ok())
+                .map(|reserve| reserve <= budget)
+                .unwrap_or(false)
+        };
+
+        // Start from the closed-form estimate where there is one. It is only
+        // off by rounding, so the bracket below stays a few base units wide.
+        let guess = self
+            .curve_type
+            .supply_for_reserve(budget)?
+            .and_then(|new_supply| {
+                new_supply
+                    .checked_mul(Decimal256::from_ratio(self.token_unit, 1u128))
+                    .ok()
+            })
+            .map(|new_supply| {
+                Uint128::try_from(new_supply.to_uint_floor())
+                    .unwrap_or(Uint128::MAX)
+                    .saturating_sub(supply)
+            })
+            .unwrap_or_default();
+
+        // Gallop away from the guess until the answer is bracketed, then
+        // binary search inside the bracket. Zero always fits.
+        let mut step = Uint128::one();
+        let (mut lo, mut hi) = if fits(guess) {
+            let mut lo = guess;
+            loop {
+                let next = lo.saturating_add(step);
+                if next == lo {
+                    return Ok(lo);
+                }
+                if !fits(next) {
+                    break (lo, next);
+                }
+                lo = next;
+                step = step.saturating_mul(Uint128::from(2u128));
+            }
+        } else {
+            let mut hi = guess;
+            loop {
+                let next = hi.saturating_sub(step);
+                if fits(next) {
+                    break (next, hi);
+                }
+                hi = next;
+                step = step.saturating_mul(Uint128::from(2u128));
+            }
+        };
+        while hi - lo > Uint128::one() {
+            let mid = lo + (hi - lo) / Uint128::from(2u128);
+            if fits(mid) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        Ok(lo)
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct CurveState {
+    /// Tokens minted through the curve and not yet sold back
+    pub supply: Uint128,
+    /// Reserve held against the curve's supply
+    pub reserve: Uint128,
+}
+
+fn overflow(e: OverflowError) -> ContractError {
+    ContractError::Std(StdError::overflow(e))
+}
+
+fn to_uint128(n: Uint256) -> Result<Uint128, ContractError> {
+    Uint128::try_from(n).map_err(|e| ContractError::Std(StdError::generic_err(e.to_string())))
+}
+
+/// e^x, computed as e^floor(x) * e^frac(x), the latter by Taylor series
+fn exp(x: Decimal256) -> Result<Decimal256, ContractError> {
+    let e = Decimal256::from_str("2.718281828459045235")?;
+    let int_part = x.to_uint_floor();
+    let frac_part = x - Decimal256::from_ratio(int_part, 1u128);
+    let int_exp = u32::try_from(to_uint128(int_part)?.u128())
+        .map_err(|e| ContractError::Std(StdError::generic_err(e.to_string())))?;
+
+    let mut sum = Decimal256::one();
+    let mut term = Decimal256::one();
+    for k in 1u128..40 {
+        term = term * frac_part / Decimal256::from_ratio(k, 1u128);
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+    }
+
+    e.checked_pow(int_exp)
+        .and_then(|n| n.checked_mul(sum))
+        .map_err(overflow)
+}
+
+/// ln(x) for x >= 1, computed as n + ln(x / e^n) with x / e^n in [1, e), the
+/// latter by the series 2 * atanh((y - 1) / (y + 1))
+fn ln(x: Decimal256) -> Result<Decimal256, ContractError> {
+    let e = Decimal256::from_str("2.718281828459045235")?;
+    if x < Decimal256::one() {
+        return Err(ContractError::Std(StdError::generic_err(
+            "ln of a value below one",
+        )));
+    }
+
+    let mut y = x;
+    let mut int_part = 0u128;
+    while y >= e {
+        y /= e;
+        int_part += 1;
+    }
+
+    let z = (y - Decimal256::one()) / (y + Decimal256::one());
+    let z_squared = z * z;
+    let mut sum = Decimal256::zero();
+    let mut power = z;
+    for k in 0u128..40 {
+        let term = power / Decimal256::from_ratio(2 * k + 1, 1u128);
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        power *= z_squared;
+    }
+
+    Ok(Decimal256::from_ratio(int_part, 1u128) + sum * Decimal256::from_ratio(2u128, 1u128))
+}
+
+/// Descending-price auction of newly minted tokens that settles at a single
+/// clearing price for all bidders
+#[cw_serde]
+pub struct Auction {
+    /// Incremented each time an auction is configured. Bids are tracked per
+    /// round.
+    pub round: u64,
+    pub reserve_denom: String,
+    /// Price, in `reserve_denom`, of one whole token when the auction starts
+    pub start_price: Uint128,
+    /// Lowest price the auction will fall to
+    pub floor_price: Uint128,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    /// If set, the price falls in discrete steps of this many seconds
+    pub step_seconds: Option<u64>,
+    /// Tokens offered, in base units
+    pub supply: Uint128,
+    /// Base units in one whole token at the time the auction was configured
+    pub token_unit: Uint128,
+    /// Recipient of auction proceeds
+    pub treasury: Addr,
+}
+
+impl Auction {
+    pub fn price(
+        &self,
+        time: Timestamp,
+    ) -> Uint128 {
+        if time <= self.start {
+            return self.start_price;
+        }
+        if time >= self.end {
+            return self.floor_price;
+        }
+        let duration = self.end.seconds() - self.start.seconds();
+        let mut elapsed = time.seconds() - self.start.seconds();
+        if let Some(step) = self.step_seconds.filter(|s| *s > 0) {
+            elapsed -= elapsed % step;
+        }
+        self.start_price - (self.start_price - self.floor_price).multiply_ratio(elapsed, duration)
+    }
+
+    /// Price at which the given total commitment buys the full supply,
+    /// rounded up so that fills never exceed the supply
+    pub fn implied_price(
+        &self,
+        total_committed: Uint128,
+    ) -> Uint128 {
+        let numerator = total_committed.full_mul(self.token_unit);
+        let denominator = Uint256::from(self.supply);
+        let price = (numerator + denominator - Uint256::one()) / denominator;
+        Uint128::try_from(price).unwrap_or(Uint128::MAX)
+    }
+
+    /// The auction closes at its end time, or earlier once demand at the
+    /// current price covers the full supply
+    pub fn is_closed(
+        &self,
+        time: Timestamp,
+        total_committed: Uint128,
+    ) -> bool {
+        time >= self.end
+            || (time >= self.start && self.price(time) <= self.implied_price(total_committed))
+    }
+
+    pub fn clearing_price(
+        &self,
+        total_committed: Uint128,
+    ) -> Uint128 {
+        self.implied_price(total_committed)
+            .max(self.floor_price)
+            .min(self.start_price)
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct AuctionState {
+    pub bidder_count: u32,
+    pub total_committed: Uint128,
+    /// Set by the first `SettleAuction`
+    pub clearing_price: Option<Uint128>,
+    /// Last bidder processed by `SettleAuction`
+    pub settle_cursor: Option<Addr>,
+    pub amount_sold: Uint128,
+    pub proceeds: Uint128,
+    pub settled: bool,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct AuctionBid {
+    pub committed: Uint128,
+    /// Tokens minted to the bidder at settlement
+    pub amount: Uint128,
+    /// Reserve refunded to the bidder at settlement
+    pub refund: Uint128,
+    pub settled: bool,
+}
+
+/// How tokens are allotted to contributors of a successful raise
+#[cw_serde]
+pub enum RaisePricing {
+    /// Each contributor receives tokens at a fixed price, in the raise denom,
+    /// per whole token
+    FixedPrice { price_per_token: Uint128 },
+    /// The given amount of tokens is split pro-rata by contribution
+    ProRata { amount: Uint128 },
+}
+
+/// Escrowed raise that mints tokens if the soft cap is met and refunds
+/// contributors otherwise
+#[cw_serde]
+pub struct Raise {
+    /// Incremented each time a raise is configured. Contributions are tracked
+    /// per round.
+    pub round: u64,
+    pub denom: String,
+    pub soft_cap: Uint128,
+    pub hard_cap: Option<Uint128>,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub pricing: RaisePricing,
+    /// Base units in one whole token at the time the raise was configured
+    pub token_unit: Uint128,
+    /// Recipient of raise proceeds, if successful
+    pub treasury: Addr,
+}
+
+impl Raise {
+    pub fn is_open(
+        &self,
+        time: Timestamp,
+    ) -> bool {
+        time >= self.start && time < self.end
+    }
+
+    pub fn is_ended(
+        &self,
+        time: Timestamp,
+    ) -> bool {
+        time >= self.end
+    }
+
+    /// Tokens owed for a contribution to a successful raise
+    pub fn allotment(
+        &self,
+        contribution: Uint128,
+        total_contributed: Uint128,
+    ) -> Uint128 {
+        match &self.pricing {
+            RaisePricing::FixedPrice { price_per_token } => {
+                contribution.multiply_ratio(self.token_unit, *price_per_token)
+            },
+            RaisePricing::ProRata { amount } => {
+                amount.multiply_ratio(contribution, total_contributed)
+            },
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct RaiseState {
+    pub contributor_count: u32,
+    /// Contributions that have been claimed or refunded
+    pub settled_count: u32,
+    pub total_contributed: Uint128,
+    pub amount_claimed: Uint128,
+    pub amount_refunded: Uint128,
+    pub proceeds_withdrawn: bool,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct Contribution {
+    pub amount: Uint128,
+    pub claimed: bool,
+    pub refunded: bool,
+}
+
+/// 1:1 receipt token backed by deposits of an underlying native denom
+#[cw_serde]
+pub struct WrappedAsset {
+    pub underlying_denom: String,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct WrappedAssetStats {
+    pub amount_minted: Uint128,
+    pub amount_burned: Uint128,
+}
+
+impl WrappedAssetStats {
+    /// Receipt tokens outstanding, each backed by a unit of deposited underlying
+    pub fn outstanding(&self) -> Uint128 {
+        self.amount_minted - self.amount_burned
+    }
+}
+
+#[cw_serde]
+pub struct StakingValidator {
+    pub address: String,
+    /// Relative share of new delegations sent to this validator
+    pub weight: u64,
+}
+
+/// Liquid staking mode, where deposits of the bond denom are delegated and
+/// receipt tokens track a share of the total bonded amount
+#[cw_serde]
+pub struct StakingConfig {
+    pub bond_denom: String,
+    pub validators: Vec<StakingValidator>,
+    /// Seconds until an unbonding claim matures. Claims are only paid from
+    /// tokens the staking module has returned, so this is an estimate of
+    /// the chain's unbonding time.
+    pub unbonding_period: u64,
+}
+
+impl StakingConfig {
+    /// Split an amount across validators by weight. Rounding dust goes to
+    /// the first validator.
+    pub fn split(
+        &self,
+        amount: Uint128,
+    ) -> Vec<(String, Uint128)> {
+        let total_weight: u64 = self.validators.iter().map(|v| v.weight).sum();
+        let mut parts: Vec<(String, Uint128)> = self
+            .validators
+            .iter()
+            .map(|v| {
+                (
+                    v.address.to_owned(),
+                    amount.multiply_ratio(v.weight, total_weight),
+                )
+            })
+            .collect();
+        let allocated: Uint128 = parts.iter().map(|(_, n)| *n).sum();
+        if let Some(first) = parts.first_mut() {
+            first.1 += amount - allocated;
+        }
+        parts.into_iter().filter(|(_, n)| !n.is_zero()).collect()
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct StakingState {
+    /// Bond denom currently delegated, including compounded rewards
+    pub total_bonded: Uint128,
+    /// Receipt tokens outstanding
+    pub total_supply: Uint128,
+    /// Undelegated tokens owed to holders of unbonding claims
+    pub total_unbonding: Uint128,
+    /// Rewards withdrawn to this contract and not yet delegated
+    pub idle_rewards: Uint128,
+}
+
+impl StakingState {
+    /// Bond denom backing the receipts: delegations plus withdrawn rewards.
+    /// Pending delegation rewards are folded into `idle_rewards` before use.
+    pub fn backing(&self) -> Result<Uint128, ContractError> {
+        self.total_bonded
+            .checked_add(self.idle_rewards)
+            .map_err(overflow)
+    }
+
+    /// Backing tokens per receipt token
+    pub fn exchange_rate(&self) -> Result<Decimal256, ContractError> {
+        Ok(if self.total_supply.is_zero() {
+            Decimal256::one()
+        } else {
+            Decimal256::from_ratio(self.backing()?, self.total_supply)
+        })
+    }
+
+    /// Receipt tokens minted for a deposit, rounded down
+    pub fn receipts_for(
+        &self,
+        bond_amount: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        let backing = self.backing()?;
+        Ok(if self.total_supply.is_zero() || backing.is_zero() {
+            bond_amount
+        } else {
+            bond_amount.multiply_ratio(self.total_supply, backing)
+        })
+    }
+
+    /// Backing tokens released for burned receipts, rounded down
+    pub fn bond_for(
+        &self,
+        receipts: Uint128,
+    ) -> Result<Uint128, ContractError> {
+        Ok(if self.total_supply.is_zero() {
+            Uint128::zero()
+        } else {
+            receipts.multiply_ratio(self.backing()?, self.total_supply)
+        })
+    }
+}
+
+#[cw_serde]
+pub struct UnbondingClaim {
+    pub amount: Uint128,
+    pub release_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct BasketComponent {
+    pub denom: String,
+    /// Base units of `denom` backing one whole basket token
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct Basket {
+    pub components: Vec<BasketComponent>,
+    /// Base units in one whole token at the time the basket was configured
+    pub token_unit: Uint128,
+    /// Seconds a rebalance proposal must wait before it can be executed
+    pub timelock_seconds: u64,
+}
+
+impl Basket {
+    /// Funds required to mint the given number of whole basket tokens
+    pub fn required_funds(
+        &self,
+        units: Uint128,
+    ) -> Result<Vec<Coin>, ContractError> {
+        self.components
+            .iter()
+            .map(|c| {
+                let amount = c.amount.checked_mul(units).map_err(overflow)?;
+                Ok(Coin::new(amount.u128(), c.denom.to_owned()))
+            })
+            .collect()
+    }
+
+    /// Holdings backing `supply` base units of the basket token, rounded up
+    /// so the supply is always fully backed
+    pub fn holdings_for(
+        &self,
+        supply: Uint128,
+    ) -> Result<Vec<Coin>, ContractError> {
+        self.components
+            .iter()
+            .map(|c| {
+                let amount = c
+                    .amount
+                    .checked_mul_ceil((supply, self.token_unit))
+                    .map_err(|e| ContractError::Std(StdError::generic_err(e.to_string())))?;
+                Ok(Coin::new(amount.u128(), c.denom.to_owned()))
+            })
+            .filter(|r| !matches!(r, Ok(coin) if coin.amount.is_zero()))
+            .collect()
+    }
+}
+
+#[cw_serde]
+pub struct BasketRebalance {
+    pub components: Vec<BasketComponent>,
+    pub executable_at: Timestamp,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct BasketState {
+    /// Basket tokens outstanding, in base units
+    pub supply: Uint128,
+    /// Components deposited by basket mints and not yet redeemed
+    pub holdings: Vec<Coin>,
+}
+
+/// Collateralized debt positions minting the tokenfactory denom against
+/// collateral valued by an oracle contract
+#[cw_serde]
+pub struct Cdp {
+    /// Contract answering `OracleQueryMsg::Price`
+    pub oracle: Addr,
+    pub collateral_denoms: Vec<String>,
+    /// Minimum collateral value / debt required to borrow or withdraw
+    pub collateral_ratio: Decimal256,
+    /// Positions below this collateral value / debt can be liquidated
+    pub liquidation_ratio: Decimal256,
+    /// Extra collateral value paid to liquidators, as a fraction of the
+    /// debt repaid
+    pub liquidation_bonus: Decimal256,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct CdpPosition {
+    pub collateral: Vec<Coin>,
+    pub debt: Uint128,
+}
+
+impl CdpPosition {
+    pub fn add_collateral(
+        &mut self,
+        coin: &Coin,
+    ) -> Result<(), ContractError> {
+        match self.collateral.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(c) => c.amount = c.amount.checked_add(coin.amount).map_err(overflow)?,
+            None => self.collateral.push(coin.to_owned()),
+        }
+        Ok(())
+    }
+
+    pub fn remove_collateral(
+        &mut self,
+        coin: &Coin,
+    ) -> Result<(), ContractError> {
+        let c = self
+            .collateral
+            .iter_mut()
+            .find(|c| c.denom == coin.denom)
+            .ok_or_else(|| ContractError::ValidationError {
+                reason: format!("no {} collateral", coin.denom),
+            })?;
+        c.amount = c.amount.checked_sub(coin.amount).map_err(overflow)?;
+        self.collateral.retain(|c| !c.amount.is_zero());
+        Ok(())
+    }
+
+    /// Collateral value / debt, or None for positions without debt
+    pub fn ratio(
+        &self,
+        collateral_value: Uint128,
+    ) -> Option<Decimal256> {
+        if self.debt.is_zero() {
+            None
+        } else {
+            Some(Decimal256::from_ratio(collateral_value, self.debt))
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.collateral.is_empty() && self.debt.is_zero()
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct CdpState {
+    pub total_debt: Uint128,
+    pub liquidation_count: u64,
+}
+
+#[cw_serde]
+pub struct LiquidationEvent {
+    pub id: u64,
+    pub owner: Addr,
+    pub liquidator: Addr,
+    pub repaid: Uint128,
+    pub collateral_seized: Vec<Coin>,
+    pub time: Timestamp,
+}
+
+#[cw_serde]
+pub enum EmissionSchedule {
+    /// Constant rate, in base units per second
+    Linear { rate_per_second: Uint128 },
+    /// Rate that halves at the end of every epoch
+    Halving {
+        initial_rate_per_second: Uint128,
+        epoch_seconds: u64,
+    },
+}
+
+/// Tokens released to a recipient over time, minted by anyone through `Emit`
+#[cw_serde]
+pub struct EmissionStream {
+    pub id: u64,
+    pub recipient: Addr,
+    pub start: Timestamp,
+    pub schedule: EmissionSchedule,
+    /// Maximum amount the stream will ever emit
+    pub total: Option<Uint128>,
+    /// Amount emitted so far
+    pub emitted: Uint128,
+}
+
+impl EmissionStream {
+    /// Amount accrued from the start of the stream until `time`, including
+    /// amounts already emitted
+    pub fn accrued(
+        &self,
+        time: Timestamp,
+    ) -> Result<Uint128, ContractError> {
+        let elapsed = time.seconds().saturating_sub(self.start.seconds());
+        let accrued = match &self.schedule {
+            EmissionSchedule::Linear { rate_per_second } => rate_per_second
+                .checked_mul(elapsed.into())
+                .map_err(overflow)?,
+            EmissionSchedule::Halving {
+                initial_rate_per_second,
+                epoch_seconds,
+            } => {
+                let mut rate = *initial_rate_per_second;
+                let mut remaining = elapsed;
+                let mut sum = Uint128::zero();
+                while remaining > 0 && !rate.is_zero() {
+                    let seconds = remaining.min(*epoch_seconds);
+                    sum = rate
+                        .checked_mul(seconds.into())
+                        .and_then(|n| sum.checked_add(n))
+                        .map_err(overflow)?;
+                    remaining -= seconds;
+                    rate >>= 1;
+                }
+                sum
+            },
+        };
+        Ok(match self.total {
+            Some(total) => accrued.min(total),
+            None => accrued,
+        })
+    }
+
+    /// Amount accrued but not yet emitted as of `time`
+    pub fn pending(
+        &self,
+        time: Timestamp,
+    ) -> Result<Uint128, ContractError> {
+        Ok(self.accrued(time)?.saturating_sub(self.emitted))
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.total
+            .map(|total| self.emitted >= total)
+            .unwrap_or(false)
+    }
+}
+
+/// Scheduled work run from end-block sudo calls (Juno clock, Neutron cron)
+#[cw_serde]
+pub struct Automation {
+    pub enabled: bool,
+    /// Max scheduled items of each kind processed per block
+    pub max_actions: u32,
+}
+
+/// Continuous issuance to a recipient between `start` and `end`
+#[cw_serde]
+pub struct PaymentStream {
+    pub id: u64,
+    pub recipient: Addr,
+    pub rate_per_second: Uint128,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    /// Amount minted to the recipient so far
+    pub withdrawn: Uint128,
+}
+
+impl PaymentStream {
+    /// Total amount streamed by `time`, including amounts already withdrawn
+    pub fn accrued(
+        &self,
+        time: Timestamp,
+    ) -> Result<Uint128, ContractError> {
+        let elapsed = time
+            .min(self.end)
+            .seconds()
+            .saturating_sub(self.start.seconds());
+        self.rate_per_second
+            .checked_mul(elapsed.into())
+            .map_err(overflow)
+    }
+
+    pub fn withdrawable(
+        &self,
+        time: Timestamp,
+    ) -> Result<Uint128, ContractError> {
+        Ok(self.accrued(time)?.saturating_sub(self.withdrawn))
+    }
+}
+
+/// Pre-approved mint that anyone can execute once `not_before` has passed
+#[cw_serde]
+pub struct ScheduledMint {
+    pub id: u64,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub not_before: Timestamp,
+}
+
+#[cw_serde]
+pub enum VoucherKeyType {
+    Secp256k1,
+    Ed25519,
+}
+
+/// Off-chain key whose signed vouchers can be redeemed for mints
+#[cw_serde]
+pub struct VoucherSigner {
+    pub key_type: VoucherKeyType,
+    pub public_key: Binary,
+}
+
+/// Minted tokens sent over IBC, kept until the packet is acknowledged
+#[cw_serde]
+pub struct RemoteTransfer {
+    pub channel_id: String,
+    /// Packet sequence, known once the transfer msg has executed
+    pub sequence: u64,
+    pub remote_recipient: String,
+    pub amount: Uint128,
+    /// Receives refunded tokens on ack failure or timeout. If not set, they
+    /// are burned instead.
+    pub refund_address: Option<Addr>,
+    pub sent_at: Timestamp,
+}
+
+/// Channel to a paired instance of this contract on another chain
+#[cw_serde]
+pub struct BridgeChannel {
+    pub channel_id: String,
+    pub connection_id: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: String,
+    /// Burned here and minted on the counterparty chain
+    pub amount_sent: Uint128,
+    /// Burned on the counterparty chain and minted here
+    pub amount_received: Uint128,
+    pub is_open: bool,
+}
+
+/// Contract notified of supply changes with `TokenFactoryHookMsg`
+#[cw_serde]
+pub struct Hook {
+    pub contract: Addr,
+    /// Whether a failing hook fails the mint or burn that triggered it
+    pub is_blocking: bool,
+}
+
+/// Settings for mirroring holder balances from BeforeSend hook calls
+#[cw_serde]
+pub struct HolderTracking {
+    /// Tokenfactory module account, which holds tokens only mid-mint and
+    /// mid-burn and so is not counted as a holder
+    pub module_address: Addr,
+}
+
+/// Mirrored bank balance of an account holding the tokenfactory denom
+#[cw_serde]
+pub struct Holder {
+    pub address: Addr,
+    pub balance: Uint128,
 }
@@ -1,11 +1,20 @@
-use cosmwasm_std::{Addr, Uint256, Uint64};
-use cw_storage_plus::{Deque, Item, Map};
+use cosmwasm_std::{Addr, Empty, Uint128, Uint256, Uint64};
+use cw_storage_plus::{Deque, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 use crate::{
     msg::{MintParams, NewDenomMetadata},
     tf::tokenfactory::TokenFactoryType,
 };
 
+use super::models::{
+    AirdropStage, Auction, AuctionBid, AuctionState, Automation, Basket, BasketRebalance,
+    BasketState, BridgeChannel, Cdp, CdpPosition, CdpState, Contribution, Curve, CurveState,
+    Cw20Migration, Cw20Wrapper, EmissionStream, Holder, HolderTracking, Hook, LiquidationEvent,
+    MigrationProgress, MintFee, PaymentStream, PendingMint, Raise, RaiseState, RemoteTransfer,
+    Sale, SaleStats, ScheduledMint, StakingConfig, StakingState, UnbondingClaim, VoucherSigner,
+    WrappedAsset, WrappedAssetStats,
+};
+
 /// Manager can mint and perform admin tasks
 pub const MANAGER: Item<Addr> = Item::new("manager");
 
@@ -18,11 +27,23 @@ pub const FULL_DENOM: Item<String> = Item::new("full_denom");
 /// Temp storage for facilitating the minting of tokens for satisfying initial balances
 pub const INITIAL_BALANCES: Deque<MintParams> = Deque::new("initial_balances");
 
+/// Total amount of initial balances minted but not yet sent out
+pub const DISTRIBUTION_AMOUNT_REMAINING: Item<Uint128> = Item::new("distribution_amount_remaining");
+
+/// Amount of the in-flight mint for a page of initial balances
+pub const INITIAL_MINT_PENDING: Item<Uint128> = Item::new("initial_mint_pending");
+
 /// Mint reply ID counter
 pub const MINT_REPLY_ID_COUNTER: Item<Uint64> = Item::new("mint_reply_id_counter");
 
 /// Temp storage for use when minting
-pub const MINT_PARAMS: Map<u64, MintParams> = Map::new("mint_params");
+pub const MINT_PARAMS: Map<u64, PendingMint> = Map::new("mint_params");
+
+/// Optional protocol fee charged on issuance
+pub const MINT_FEE: Item<MintFee> = Item::new("mint_fee");
+
+/// Optional cap on the total amount minted through this contract
+pub const MAX_SUPPLY: Item<Uint128> = Item::new("max_supply");
 
 /// Denom metadata set through this contract
 pub const DENOM_METADATA: Item<NewDenomMetadata> = Item::new("denom_metadata");
@@ -32,3 +53,227 @@ pub const AMOUNT_MINTED: Item<Uint256> = Item::new("amount_minted");
 
 /// Total amount of token burned through this contract
 pub const AMOUNT_BURNED: Item<Uint256> = Item::new("amount_burned");
+
+/// Total amount of token minted as mint fees, included in `AMOUNT_MINTED`
+pub const AMOUNT_FEES_MINTED: Item<Uint256> = Item::new("amount_fees_minted");
+
+/// Cw20 token that holders can wrap into the tokenfactory denom
+pub const CW20_WRAPPER: Item<Cw20Wrapper> = Item::new("cw20_wrapper");
+
+/// Amount of the wrapped cw20 token currently held by this contract
+pub const CW20_AMOUNT_WRAPPED: Item<Uint128> = Item::new("cw20_amount_wrapped");
+
+/// Legacy cw20 token whose holders are being migrated to the tokenfactory denom
+pub const CW20_MIGRATION: Item<Cw20Migration> = Item::new("cw20_migration");
+
+/// Unclaimed tokenfactory amount owed to each legacy cw20 holder
+pub const MIGRATION_UNCLAIMED: Map<&Addr, Uint128> = Map::new("migration_unclaimed");
+
+/// Tokenfactory amount already delivered to each legacy cw20 holder
+pub const MIGRATION_CLAIMED: Map<&Addr, Uint128> = Map::new("migration_claimed");
+
+/// Aggregate legacy cw20 migration totals
+pub const MIGRATION_PROGRESS: Item<MigrationProgress> = Item::new("migration_progress");
+
+/// ID of the most recently registered airdrop stage
+pub const AIRDROP_LATEST_STAGE: Item<u32> = Item::new("airdrop_latest_stage");
+
+/// Airdrop stages by ID
+pub const AIRDROP_STAGES: Map<u32, AirdropStage> = Map::new("airdrop_stages");
+
+/// Amount claimed by each address in each airdrop stage
+pub const AIRDROP_CLAIMS: Map<(u32, &Addr), Uint128> = Map::new("airdrop_claims");
+
+/// Fixed-price token sale config
+pub const SALE: Item<Sale> = Item::new("sale");
+
+/// Aggregate fixed-price sale totals
+pub const SALE_STATS: Item<SaleStats> = Item::new("sale_stats");
+
+/// Amount of tokens bought by each buyer, keyed by sale round
+pub const SALE_PURCHASES: Map<(u64, &Addr), Uint128> = Map::new("sale_purchases");
+
+/// Bonding curve config
+pub const CURVE: Item<Curve> = Item::new("curve");
+
+/// Supply and reserve held by the bonding curve
+pub const CURVE_STATE: Item<CurveState> = Item::new("curve_state");
+
+/// Dutch auction config
+pub const AUCTION: Item<Auction> = Item::new("auction");
+
+/// Aggregate Dutch auction totals and settlement progress
+pub const AUCTION_STATE: Item<AuctionState> = Item::new("auction_state");
+
+/// Dutch auction bids by auction round and bidder
+pub const AUCTION_BIDS: Map<(u64, &Addr), AuctionBid> = Map::new("auction_bids");
+
+/// Soft-cap raise configs by round. Earlier rounds are kept so that their
+/// contributions can still be claimed or refunded.
+pub const RAISES: Map<u64, Raise> = Map::new("raises");
+
+/// Aggregate soft-cap raise totals by round
+pub const RAISE_STATES: Map<u64, RaiseState> = Map::new("raise_states");
+
+/// Round of the most recently configured raise
+pub const RAISE_LATEST_ROUND: Item<u64> = Item::new("raise_latest_round");
+
+/// Soft-cap raise contributions by raise round and contributor
+pub const RAISE_CONTRIBUTIONS: Map<(u64, &Addr), Contribution> = Map::new("raise_contributions");
+
+/// Underlying denom for wrapped-asset mode
+pub const WRAPPED_ASSET: Item<WrappedAsset> = Item::new("wrapped_asset");
+
+/// Receipt tokens minted and burned in wrapped-asset mode
+pub const WRAPPED_ASSET_STATS: Item<WrappedAssetStats> = Item::new("wrapped_asset_stats");
+
+/// Validator set and bond denom for liquid staking mode
+pub const STAKING_CONFIG: Item<StakingConfig> = Item::new("staking_config");
+
+/// Totals backing the liquid staking exchange rate
+pub const STAKING_STATE: Item<StakingState> = Item::new("staking_state");
+
+/// Pending unbonding claims per address
+pub const UNBONDING_CLAIMS: Map<&Addr, Vec<UnbondingClaim>> = Map::new("unbonding_claims");
+
+/// Component composition of the basket token
+pub const BASKET: Item<Basket> = Item::new("basket");
+
+/// Basket tokens outstanding
+pub const BASKET_STATE: Item<BasketState> = Item::new("basket_state");
+
+/// Timelocked change to the basket composition
+pub const BASKET_REBALANCE: Item<BasketRebalance> = Item::new("basket_rebalance");
+
+/// Oracle and risk parameters for collateralized minting
+pub const CDP: Item<Cdp> = Item::new("cdp");
+
+/// Aggregate debt and liquidation count across positions
+pub const CDP_STATE: Item<CdpState> = Item::new("cdp_state");
+
+/// Collateralized debt position per owner
+pub const CDP_POSITIONS: Map<&Addr, CdpPosition> = Map::new("cdp_positions");
+
+/// Log of liquidations by sequential ID
+pub const CDP_LIQUIDATIONS: Map<u64, LiquidationEvent> = Map::new("cdp_liquidations");
+
+/// Emission streams by ID
+pub const EMISSION_STREAMS: Map<u64, EmissionStream> = Map::new("emission_streams");
+
+/// ID of the most recently added emission stream
+pub const EMISSION_ID_COUNTER: Item<u64> = Item::new("emission_id_counter");
+
+/// Last emission stream processed by `Emit`, so that each call picks up
+/// where the previous one left off
+pub const EMISSION_CURSOR: Item<u64> = Item::new("emission_cursor");
+
+/// End-block automation settings
+pub const AUTOMATION: Item<Automation> = Item::new("automation");
+
+/// Last emission stream processed by automation, so that each block picks
+/// up where the previous one left off
+pub const AUTOMATION_EMISSION_CURSOR: Item<u64> = Item::new("automation_emission_cursor");
+
+/// ID of the most recently created payment stream
+pub const PAYMENT_STREAM_ID_COUNTER: Item<u64> = Item::new("payment_stream_id_counter");
+
+pub struct PaymentStreamIndexes<'a> {
+    pub recipient: MultiIndex<'a, Addr, PaymentStream, u64>,
+}
+
+impl<'a> IndexList<PaymentStream> for PaymentStreamIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PaymentStream>> + '_> {
+        let v: Vec<&dyn Index<PaymentStream>> = vec![&self.recipient];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Payment streams by ID, indexed by recipient
+pub fn payment_streams<'a>() -> IndexedMap<'a, u64, PaymentStream, PaymentStreamIndexes<'a>> {
+    IndexedMap::new(
+        "payment_streams",
+        PaymentStreamIndexes {
+            recipient: MultiIndex::new(
+                |_, s| s.recipient.to_owned(),
+                "payment_streams",
+                "payment_streams__recipient",
+            ),
+        },
+    )
+}
+
+/// ID of the most recently scheduled mint
+pub const SCHEDULED_MINT_ID_COUNTER: Item<u64> = Item::new("scheduled_mint_id_counter");
+
+pub struct ScheduledMintIndexes<'a> {
+    pub not_before: MultiIndex<'a, u64, ScheduledMint, u64>,
+}
+
+impl<'a> IndexList<ScheduledMint> for ScheduledMintIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<ScheduledMint>> + '_> {
+        let v: Vec<&dyn Index<ScheduledMint>> = vec![&self.not_before];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Scheduled mints by ID, indexed by the time they become due
+pub fn scheduled_mints<'a>() -> IndexedMap<'a, u64, ScheduledMint, ScheduledMintIndexes<'a>> {
+    IndexedMap::new(
+        "scheduled_mints",
+        ScheduledMintIndexes {
+            not_before: MultiIndex::new(
+                |_, m| m.not_before.seconds(),
+                "scheduled_mints",
+                "scheduled_mints__not_before",
+            ),
+        },
+    )
+}
+
+/// Key that signs mint vouchers
+pub const VOUCHER_SIGNER: Item<VoucherSigner> = Item::new("voucher_signer");
+
+/// Voucher nonces already redeemed
+pub const VOUCHER_NONCES: Map<u64, Empty> = Map::new("voucher_nonces");
+
+/// Remote transfer sent from the current mint reply, awaiting its sequence
+pub const REMOTE_TRANSFER_IN_FLIGHT: Item<RemoteTransfer> = Item::new("remote_transfer_in_flight");
+
+/// Unacknowledged remote transfers by (channel ID, packet sequence)
+pub const REMOTE_TRANSFERS: Map<(&str, u64), RemoteTransfer> = Map::new("remote_transfers");
+
+/// Counterparty (connection ID, port ID) pairs allowed to open bridge channels
+pub const BRIDGE_PEERS: Map<(&str, &str), Empty> = Map::new("bridge_peers");
+
+/// Bridge channels by local channel ID
+pub const BRIDGE_CHANNELS: Map<&str, BridgeChannel> = Map::new("bridge_channels");
+
+/// Contracts notified of mints and burns
+pub const HOOKS: Map<&Addr, Hook> = Map::new("hooks");
+
+/// Holder tracking settings, set once holder tracking is enabled
+pub const HOLDER_TRACKING: Item<HolderTracking> = Item::new("holder_tracking");
+
+/// Number of accounts with a non-zero mirrored balance
+pub const HOLDER_COUNT: Item<u64> = Item::new("holder_count");
+
+pub struct HolderIndexes<'a> {
+    pub balance: MultiIndex<'a, u128, Holder, Addr>,
+}
+
+impl<'a> IndexList<Holder> for HolderIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Holder>> + '_> {
+        let v: Vec<&dyn Index<Holder>> = vec![&self.balance];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Mirrored balances of non-zero holders by address, indexed by balance
+pub fn holders<'a>() -> IndexedMap<'a, &'a Addr, Holder, HolderIndexes<'a>> {
+    IndexedMap::new(
+        "holders",
+        HolderIndexes {
+            balance: MultiIndex::new(|_, h| h.balance.u128(), "holders", "holders__balance"),
+        },
+    )
+}
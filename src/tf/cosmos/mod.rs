@@ -1,3 +1,5 @@
 //
 pub mod common;
 pub mod denom;
+pub mod staking;
+pub mod transfer;
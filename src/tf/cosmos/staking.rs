@@ -0,0 +1,88 @@
+use osmosis_std_derive::CosmwasmExt;
+
+// see https://github.com/cosmos/cosmos-sdk/blob/main/proto/cosmos/staking/v1beta1/query.proto
+
+/// QueryDelegatorUnbondingDelegationsRequest is the request type for the
+/// Query/DelegatorUnbondingDelegations RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsRequest")]
+#[proto_query(
+    path = "/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations",
+    response_type = QueryDelegatorUnbondingDelegationsResponse
+)]
+pub struct QueryDelegatorUnbondingDelegationsRequest {
+    #[prost(string, tag = "1")]
+    pub delegator_addr: ::prost::alloc::string::String,
+}
+
+/// QueryDelegatorUnbondingDelegationsResponse is the response type for the
+/// Query/DelegatorUnbondingDelegations RPC method. Pagination is omitted, as a
+/// delegator has at most 7 entries per validator.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsResponse")]
+pub struct QueryDelegatorUnbondingDelegationsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub unbonding_responses: ::prost::alloc::vec::Vec<UnbondingDelegation>,
+}
+
+/// UnbondingDelegation stores all of a single delegator's unbonding bonds
+/// for a single validator in an time-ordered list.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/cosmos.staking.v1beta1.UnbondingDelegation")]
+pub struct UnbondingDelegation {
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub validator_address: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub entries: ::prost::alloc::vec::Vec<UnbondingDelegationEntry>,
+}
+
+/// UnbondingDelegationEntry defines an unbonding object with relevant
+/// metadata. Only the balances are decoded.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/cosmos.staking.v1beta1.UnbondingDelegationEntry")]
+pub struct UnbondingDelegationEntry {
+    /// Tokens initially scheduled to receive at completion
+    #[prost(string, tag = "3")]
+    pub initial_balance: ::prost::alloc::string::String,
+    /// Tokens to receive at completion
+    #[prost(string, tag = "4")]
+    pub balance: ::prost::alloc::string::String,
+}
@@ -0,0 +1,76 @@
+use osmosis_std_derive::CosmwasmExt;
+
+use super::denom::Coin;
+
+// see https://github.com/cosmos/ibc-go/blob/main/proto/ibc/applications/transfer/v1/tx.proto
+
+/// Height is a monotonically increasing data type that can be compared against
+/// another Height for the purposes of updating and freezing clients.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/ibc.core.client.v1.Height")]
+pub struct Height {
+    #[prost(uint64, tag = "1")]
+    pub revision_number: u64,
+    #[prost(uint64, tag = "2")]
+    pub revision_height: u64,
+}
+
+/// MsgTransfer defines a msg to transfer fungible tokens (i.e Coins) between
+/// ICS20 enabled chains. Unlike `IbcMsg::Transfer`, it carries a memo.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/ibc.applications.transfer.v1.MsgTransfer")]
+pub struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    pub source_port: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub source_channel: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub token: ::core::option::Option<Coin>,
+    #[prost(string, tag = "4")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub receiver: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: ::core::option::Option<Height>,
+    /// Timeout in nanoseconds since the unix epoch
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    pub memo: ::prost::alloc::string::String,
+}
+
+/// MsgTransferResponse defines the Msg/Transfer response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/ibc.applications.transfer.v1.MsgTransferResponse")]
+pub struct MsgTransferResponse {
+    /// sequence number of the transfer packet sent
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+}
@@ -0,0 +1,24 @@
+use osmosis_std_derive::CosmwasmExt;
+
+// see https://github.com/CosmosContracts/juno/blob/main/proto/juno/clock/v1/tx.proto
+
+/// MsgRegisterClockContract registers a contract to receive a `clock_end_block`
+/// sudo message at the end of every block. The sender must be the contract's
+/// admin or creator.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    CosmwasmExt,
+)]
+#[proto_message(type_url = "/juno.clock.v1.MsgRegisterClockContract")]
+pub struct MsgRegisterClockContract {
+    #[prost(string, tag = "1")]
+    pub sender_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub contract_address: ::prost::alloc::string::String,
+}
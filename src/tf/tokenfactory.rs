@@ -46,8 +46,8 @@ impl FromStr for TokenFactoryType {
     }
 }
 impl TokenFactoryType {
-    pub fn from_chain_id(chain_id: &String) -> Self {
-        match chain_id.as_str() {
+    pub fn from_chain_id(chain_id: &str) -> Self {
+        match chain_id {
             "juno-1" | "testing" => Self::Juno,
             "osmosis-1" | "stargaze-1" => Self::Osmosis,
             "injective-1" => Self::Injective,
@@ -304,6 +304,39 @@ impl TokenFactoryType {
         }
     }
 
+    /// Msg to make `contract` the denom's BeforeSend hook, on factories that
+    /// support hooks
+    pub fn set_before_send_hook(
+        &self,
+        sender: Addr,
+        denom: &str,
+        contract: Addr,
+    ) -> Option<CosmosMsg> {
+        match self {
+            TokenFactoryType::Osmosis => Some(<osmosis::denom::MsgSetBeforeSendHook as Into<
+                CosmosMsg,
+            >>::into(
+                osmosis::denom::MsgSetBeforeSendHook {
+                    sender: sender.to_string(),
+                    denom: denom.to_string(),
+                    cosmwasm_address: contract.to_string(),
+                },
+            )),
+            TokenFactoryType::Juno => Some(<juno::denom::MsgSetBeforeSendHook as Into<
+                CosmosMsg,
+            >>::into(
+                juno::denom::MsgSetBeforeSendHook {
+                    sender: sender.to_string(),
+                    denom: denom.to_string(),
+                    cosmwasm_address: contract.to_string(),
+                },
+            )),
+            TokenFactoryType::CosmWasm | TokenFactoryType::Kujira | TokenFactoryType::Injective => {
+                None
+            },
+        }
+    }
+
     pub fn admin_path(&self) -> String {
         match self {
             Self::CosmWasm => "/cosmwasm.tokenfactory.v1.Query/DenomInfo",
@@ -0,0 +1,196 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_std::{Addr, Uint128};
+use cw_contract::msg::ExecuteMsg;
+use cw_multi_test::error::AnyResult;
+use sha2::{Digest, Sha256};
+
+fn leaf(
+    address: &Addr,
+    amount: u128,
+) -> [u8; 32] {
+    Sha256::digest(format!("{}{}", address, amount).as_bytes()).into()
+}
+
+fn hash_pair(
+    a: [u8; 32],
+    b: [u8; 32],
+) -> [u8; 32] {
+    let (first, second) = if a < b { (a, b) } else { (b, a) };
+    Sha256::new()
+        .chain_update(first)
+        .chain_update(second)
+        .finalize()
+        .into()
+}
+
+/// Merkle root over `leaves`, with the proof for each leaf. An odd node out
+/// is carried up to the next level unhashed.
+fn merkle_tree(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<String>>) {
+    let mut proofs = vec![vec![]; leaves.len()];
+    // Indices into `leaves` covered by each node of the current level
+    let mut level: Vec<([u8; 32], Vec<usize>)> = leaves
+        .iter()
+        .enumerate()
+        .map(|(i, l)| (*l, vec![i]))
+        .collect();
+
+    while level.len() > 1 {
+        let mut next = vec![];
+        for pair in level.chunks(2) {
+            match pair {
+                [(a, a_leaves), (b, b_leaves)] => {
+                    for i in a_leaves {
+                        proofs[*i].push(hex::encode(b));
+                    }
+                    for i in b_leaves {
+                        proofs[*i].push(hex::encode(a));
+                    }
+                    next.push((
+                        hash_pair(*a, *b),
+                        [a_leaves.clone(), b_leaves.clone()].concat(),
+                    ));
+                },
+                [node] => next.push(node.clone()),
+                _ => unreachable!(),
+            }
+        }
+        level = next;
+    }
+
+    (level[0].0, proofs)
+}
+
+fn register(
+    suite: &mut Suite,
+    root: [u8; 32],
+    total_amount: u128,
+) {
+    suite
+        .execute_as_manager(ExecuteMsg::RegisterAirdrop {
+            merkle_root: hex::encode(root),
+            total_amount: Uint128::new(total_amount),
+            start: None,
+            expiration: None,
+        })
+        .unwrap();
+}
+
+fn claim(
+    suite: &mut Suite,
+    claimant: &Addr,
+    amount: u128,
+    proof: Vec<String>,
+) -> AnyResult<()> {
+    suite
+        .execute(
+            claimant,
+            ExecuteMsg::ClaimAirdrop {
+                stage: 1,
+                amount: Uint128::new(amount),
+                proof,
+            },
+            &[],
+        )
+        .map(|_| ())
+}
+
+#[test]
+fn leaf_is_sha256_of_address_and_amount() {
+    let mut suite = Suite::new();
+    let alice = suite.addr("alice");
+
+    // A single leaf is its own root, claimed with an empty proof
+    register(&mut suite, leaf(&alice, 100), 100);
+    claim(&mut suite, &alice, 100, vec![]).unwrap();
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(100));
+}
+
+#[test]
+fn claims_verify_against_sorted_pair_proofs() {
+    let mut suite = Suite::new();
+    let claimants: Vec<(Addr, u128)> = ["alice", "bob", "carol"]
+        .iter()
+        .zip([100, 200, 300])
+        .map(|(name, amount)| (suite.addr(name), amount))
+        .collect();
+    let leaves: Vec<[u8; 32]> = claimants.iter().map(|(a, n)| leaf(a, *n)).collect();
+    let (root, proofs) = merkle_tree(&leaves);
+    register(&mut suite, root, 600);
+
+    let (alice, _) = &claimants[0];
+    let (bob, _) = &claimants[1];
+
+    // Wrong amount, and another claimant's proof
+    claim(&mut suite, alice, 101, proofs[0].clone()).unwrap_err();
+    claim(&mut suite, alice, 200, proofs[1].clone()).unwrap_err();
+    claim(&mut suite, bob, 100, proofs[0].clone()).unwrap_err();
+
+    for ((claimant, amount), proof) in claimants.iter().zip(proofs.iter()) {
+        claim(&mut suite, claimant, *amount, proof.clone()).unwrap();
+        assert_eq!(suite.balance(claimant, &suite.denom), Uint128::new(*amount));
+    }
+}
+
+#[test]
+fn double_claim_is_rejected() {
+    let mut suite = Suite::new();
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+    let (root, proofs) = merkle_tree(&[leaf(&alice, 100), leaf(&bob, 200)]);
+    register(&mut suite, root, 300);
+
+    claim(&mut suite, &alice, 100, proofs[0].clone()).unwrap();
+    let err = claim(&mut suite, &alice, 100, proofs[0].clone()).unwrap_err();
+    assert!(err.root_cause().to_string().contains("already claimed"));
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(100));
+}
+
+#[test]
+fn claims_cannot_exceed_total_amount() {
+    let mut suite = Suite::new();
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+    let (root, proofs) = merkle_tree(&[leaf(&alice, 100), leaf(&bob, 200)]);
+    register(&mut suite, root, 250);
+
+    claim(&mut suite, &bob, 200, proofs[1].clone()).unwrap();
+    let err = claim(&mut suite, &alice, 100, proofs[0].clone()).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("total amount exceeded"));
+}
+#[test]
+fn known_root_and_proof() {
+    let mut suite = Suite::new();
+    // Tree over alice 100, bob 200, carol 300, computed independently
+    let root = "93d89ff4f92f784a9cd53c1955c6f4fd88660efe0cc2c9fc61385ac8da3c3520";
+    let alice_proof = [
+        "6594f1b6a299b02282a8be341f954648efd6ceac5bd0a32ce6614ada13f05e94",
+        "cd32331e81a97d5feab9f390994b2d28282455e3283f9b379975dd64b64c7ed6",
+    ];
+    let alice = suite.addr("alice");
+    assert_eq!(
+        alice.as_str(),
+        "osmo190vqdjtlpcq27xslcveglfmr4ynfwg7gmw86cnun4acakxrdd6gqt4z2e4"
+    );
+
+    suite
+        .execute_as_manager(ExecuteMsg::RegisterAirdrop {
+            merkle_root: root.to_uppercase(),
+            total_amount: Uint128::new(600),
+            start: None,
+            expiration: None,
+        })
+        .unwrap();
+    claim(
+        &mut suite,
+        &alice,
+        100,
+        alice_proof.iter().map(|n| n.to_string()).collect(),
+    )
+    .unwrap();
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(100));
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_contract::msg::ExecuteMsg;
+
+const RESERVE_DENOM: &str = "uusdc";
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+fn setup() -> (Suite, Addr) {
+    let mut suite = Suite::new();
+    let treasury = suite.addr("treasury");
+    let now = suite.app.block_info().time;
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureAuction {
+            reserve_denom: RESERVE_DENOM.to_owned(),
+            start_price: Uint128::new(2_000_000),
+            floor_price: Uint128::new(1_000_000),
+            start: now,
+            end: now.plus_seconds(100),
+            step_seconds: None,
+            supply: Uint128::new(1_000),
+            treasury: Some(treasury.to_owned()),
+        })
+        .unwrap();
+    (suite, treasury)
+}
+
+fn bid(
+    suite: &mut Suite,
+    bidder: &Addr,
+    amount: u128,
+) {
+    suite.fund(bidder, coins(amount, RESERVE_DENOM));
+    suite
+        .execute(
+            bidder,
+            ExecuteMsg::PlaceBid { recipient: None },
+            &coins(amount, RESERVE_DENOM),
+        )
+        .unwrap();
+}
+
+#[test]
+fn settles_at_clearing_price() {
+    let (mut suite, treasury) = setup();
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+    bid(&mut suite, &alice, 300);
+    bid(&mut suite, &bob, 200);
+
+    suite.advance_seconds(100);
+    let anyone = suite.addr("anyone");
+    suite
+        .execute(&anyone, ExecuteMsg::SettleAuction { limit: None }, &[])
+        .unwrap();
+
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(300));
+    assert_eq!(suite.balance(&bob, &suite.denom), Uint128::new(200));
+    assert_eq!(suite.balance(&treasury, RESERVE_DENOM), Uint128::new(500));
+}
+
+#[test]
+fn unsettled_bids_can_be_reclaimed() {
+    let (mut suite, treasury) = setup();
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+    bid(&mut suite, &alice, 300);
+    bid(&mut suite, &bob, 200);
+
+    // Not before the reclaim delay has passed
+    suite.advance_seconds(100);
+    suite
+        .execute(&alice, ExecuteMsg::ReclaimBid {}, &[])
+        .unwrap_err();
+
+    suite.advance_seconds(WEEK);
+    suite
+        .execute(&alice, ExecuteMsg::ReclaimBid {}, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&alice, RESERVE_DENOM), Uint128::new(300));
+    suite
+        .execute(&alice, ExecuteMsg::ReclaimBid {}, &[])
+        .unwrap_err();
+
+    // Settlement skips the reclaimed bid
+    let anyone = suite.addr("anyone");
+    suite
+        .execute(&anyone, ExecuteMsg::SettleAuction { limit: None }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::zero());
+    assert_eq!(suite.balance(&bob, &suite.denom), Uint128::new(200));
+    assert_eq!(suite.balance(&treasury, RESERVE_DENOM), Uint128::new(200));
+
+    suite
+        .execute(&bob, ExecuteMsg::ReclaimBid {}, &[])
+        .unwrap_err();
+}
+
+#[test]
+fn settlement_stays_within_max_supply() {
+    let mut suite = Suite::new();
+    let now = suite.app.block_info().time;
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(999)),
+        })
+        .unwrap();
+    let configure = |start| ExecuteMsg::ConfigureAuction {
+        reserve_denom: RESERVE_DENOM.to_owned(),
+        start_price: Uint128::new(2_000_000),
+        floor_price: Uint128::new(1_000_000),
+        start,
+        end: now.plus_seconds(100),
+        step_seconds: None,
+        supply: Uint128::new(1_000),
+        treasury: None,
+    };
+    suite.execute_as_manager(configure(now)).unwrap_err();
+
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(1_000)),
+        })
+        .unwrap();
+    suite.execute_as_manager(configure(now)).unwrap();
+
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+    bid(&mut suite, &alice, 300);
+    bid(&mut suite, &bob, 200);
+
+    // Lowered after the auction was configured
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(400)),
+        })
+        .unwrap();
+    suite.advance_seconds(100);
+    let anyone = suite.addr("anyone");
+    suite
+        .execute(&anyone, ExecuteMsg::SettleAuction { limit: None }, &[])
+        .unwrap();
+
+    let minted = suite.balance(&alice, &suite.denom) + suite.balance(&bob, &suite.denom);
+    let refunded = suite.balance(&alice, RESERVE_DENOM) + suite.balance(&bob, RESERVE_DENOM);
+    assert_eq!(minted, Uint128::new(400));
+    assert_eq!(refunded, Uint128::new(100));
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_std::{coin, coins, Uint128};
+use cw_contract::{msg::ExecuteMsg, state::models::BasketComponent};
+
+const TIMELOCK: u64 = 100;
+
+fn component(
+    denom: &str,
+    amount: u128,
+) -> BasketComponent {
+    BasketComponent {
+        denom: denom.to_owned(),
+        amount: Uint128::new(amount),
+    }
+}
+
+#[test]
+fn rebalance_settles_outstanding_holdings() {
+    let mut suite = Suite::new();
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureBasket {
+            components: vec![component("uatom", 2), component("uosmo", 3)],
+            timelock_seconds: TIMELOCK,
+        })
+        .unwrap();
+
+    let alice = suite.addr("alice");
+    suite.fund(&alice, vec![coin(20, "uatom"), coin(30, "uosmo")]);
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::MintBasket {
+                units: Uint128::new(10),
+                recipient: None,
+            },
+            &[coin(20, "uatom"), coin(30, "uosmo")],
+        )
+        .unwrap();
+
+    suite
+        .execute_as_manager(ExecuteMsg::ProposeBasketRebalance {
+            components: vec![component("uatom", 1), component("uusdc", 5)],
+        })
+        .unwrap();
+
+    let keeper = suite.addr("keeper");
+    suite.fund(&keeper, coins(100, "uusdc"));
+    suite
+        .execute(
+            &keeper,
+            ExecuteMsg::ExecuteBasketRebalance {},
+            &coins(50, "uusdc"),
+        )
+        .unwrap_err();
+
+    suite.advance_seconds(TIMELOCK);
+
+    // The executor must bring exactly what the supply is short of
+    suite
+        .execute(
+            &keeper,
+            ExecuteMsg::ExecuteBasketRebalance {},
+            &coins(49, "uusdc"),
+        )
+        .unwrap_err();
+    suite
+        .execute(
+            &keeper,
+            ExecuteMsg::ExecuteBasketRebalance {},
+            &coins(50, "uusdc"),
+        )
+        .unwrap();
+    assert_eq!(suite.balance(&keeper, "uatom"), Uint128::new(10));
+    assert_eq!(suite.balance(&keeper, "uosmo"), Uint128::new(30));
+
+    // Holders redeem against the new composition
+    let denom = suite.denom.to_owned();
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::RedeemBasket {},
+            &coins(10_000_000, &denom),
+        )
+        .unwrap();
+    assert_eq!(suite.balance(&alice, "uatom"), Uint128::new(10));
+    assert_eq!(suite.balance(&alice, "uusdc"), Uint128::new(50));
+    assert_eq!(suite.balance(&alice, "uosmo"), Uint128::zero());
+}
@@ -0,0 +1,321 @@
+//! The multi-test IBC module can't relay packets, so these drive the IBC
+//! entry points directly against mock dependencies.
+
+use cosmwasm_std::{
+    coins, from_json,
+    testing::{
+        mock_dependencies, mock_env, mock_ibc_channel_connect_ack, mock_ibc_channel_open_try,
+        mock_ibc_packet_ack, mock_ibc_packet_recv, mock_ibc_packet_timeout, mock_info, MockApi,
+        MockQuerier, MockStorage,
+    },
+    Addr, BankMsg, CosmosMsg, IbcAcknowledgement, IbcMsg, IbcOrder, Int256, OwnedDeps, Reply,
+    Response, SubMsg, SubMsgResponse, SubMsgResult, Uint128,
+};
+use cw_contract::{
+    contract::{execute, instantiate, query, reply},
+    error::ContractError,
+    ibc::{
+        ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ibc_packet_receive,
+        ibc_packet_timeout, BRIDGE_VERSION,
+    },
+    msg::{
+        BridgeAck, BridgeChannelResponse, BridgePacket, ExecuteMsg, InstantiateMsg,
+        NewDenomMetadata, QueryMsg,
+    },
+};
+
+type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+const CHANNEL: &str = "channel-0";
+// Connection and counterparty port of `mock_ibc_channel`
+const CONNECTION: &str = "connection-2";
+const PEER_PORT: &str = "their_port";
+
+fn denom() -> String {
+    format!("factory/{}/tkn", mock_env().contract.address)
+}
+
+fn setup() -> Deps {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("manager", &[]),
+        InstantiateMsg {
+            manager: None,
+            factory: None,
+            initial_balances: None,
+            metadata: NewDenomMetadata {
+                symbol: "TKN".to_owned(),
+                decimals: 6,
+                name: "Token".to_owned(),
+                description: None,
+                uri: None,
+            },
+            mint_fee: None,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("manager", &[]),
+        ExecuteMsg::SetBridgePeer {
+            connection_id: CONNECTION.to_owned(),
+            port_id: PEER_PORT.to_owned(),
+            is_allowed: true,
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn connect(deps: &mut Deps) {
+    let open = mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, BRIDGE_VERSION);
+    ibc_channel_open(deps.as_mut(), mock_env(), open).unwrap();
+    let connect = mock_ibc_channel_connect_ack(CHANNEL, IbcOrder::Unordered, BRIDGE_VERSION);
+    ibc_channel_connect(deps.as_mut(), mock_env(), connect).unwrap();
+}
+
+fn channel(deps: &Deps) -> BridgeChannelResponse {
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::BridgeChannel {
+            channel_id: CHANNEL.to_owned(),
+        },
+    )
+    .unwrap();
+    from_json(res).unwrap()
+}
+
+/// Complete the factory mint in `submsg`, returning where its reply sent the
+/// minted coins
+fn complete_mint(
+    deps: &mut Deps,
+    submsg: &SubMsg,
+) -> (String, Uint128) {
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: submsg.id,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+            assert_eq!(amount[0].denom, denom());
+            (to_address.to_owned(), amount[0].amount)
+        },
+        msg => panic!("expected a bank send, got {:?}", msg),
+    }
+}
+
+fn transfer(
+    deps: &mut Deps,
+    sender: &str,
+    amount: u128,
+) -> (Response, BridgePacket) {
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &coins(amount, denom())),
+        ExecuteMsg::BridgeTransfer {
+            channel_id: CHANNEL.to_owned(),
+            remote_recipient: "remote".to_owned(),
+            timeout: None,
+        },
+    )
+    .unwrap();
+    let packet = res
+        .messages
+        .iter()
+        .find_map(|m| match &m.msg {
+            CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id, data, ..
+            }) => {
+                assert_eq!(channel_id, CHANNEL);
+                Some(from_json(data).unwrap())
+            },
+            _ => None,
+        })
+        .expect("bridge transfer sends a packet");
+    (res, packet)
+}
+
+#[test]
+fn handshake_validates_version_order_and_peer() {
+    let mut deps = setup();
+
+    let wrong_version = mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, "ics20-1");
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), wrong_version).unwrap_err();
+    assert!(err.to_string().contains("version"));
+
+    let ordered = mock_ibc_channel_open_try(CHANNEL, IbcOrder::Ordered, BRIDGE_VERSION);
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), ordered).unwrap_err();
+    assert!(err.to_string().contains("unordered"));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("manager", &[]),
+        ExecuteMsg::SetBridgePeer {
+            connection_id: CONNECTION.to_owned(),
+            port_id: PEER_PORT.to_owned(),
+            is_allowed: false,
+        },
+    )
+    .unwrap();
+    let unknown_peer = mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, BRIDGE_VERSION);
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), unknown_peer).unwrap_err();
+    assert!(matches!(err, ContractError::NotAuthorized { .. }));
+    let unknown_peer = mock_ibc_channel_connect_ack(CHANNEL, IbcOrder::Unordered, BRIDGE_VERSION);
+    ibc_channel_connect(deps.as_mut(), mock_env(), unknown_peer).unwrap_err();
+
+    let mut deps = setup();
+    connect(&mut deps);
+    let channel = channel(&deps).channel;
+    assert!(channel.is_open);
+    assert_eq!(channel.connection_id, CONNECTION);
+    assert_eq!(channel.counterparty_port_id, PEER_PORT);
+}
+
+#[test]
+fn transfer_burns_and_receive_mints() {
+    let mut deps = setup();
+    connect(&mut deps);
+
+    let (res, packet) = transfer(&mut deps, "sender", 100);
+    assert_eq!(packet.sender, "sender");
+    assert_eq!(packet.recipient, "remote");
+    assert_eq!(packet.amount, Uint128::new(100));
+    assert!(res.messages.iter().any(|m| matches!(
+        &m.msg,
+        CosmosMsg::Stargate { type_url, .. } if type_url.ends_with("MsgBurn")
+    )));
+
+    // The counterparty burns and sends a packet back the other way
+    let recipient = Addr::unchecked("recipient");
+    let inbound = BridgePacket {
+        sender: "remote".to_owned(),
+        recipient: recipient.to_string(),
+        amount: Uint128::new(40),
+    };
+    let recv = mock_ibc_packet_recv(CHANNEL, &inbound).unwrap();
+    let res = ibc_packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+    assert!(matches!(
+        from_json(&res.acknowledgement).unwrap(),
+        BridgeAck::Result(_)
+    ));
+    assert_eq!(
+        complete_mint(&mut deps, &res.messages[0]),
+        (recipient.to_string(), Uint128::new(40))
+    );
+
+    let channel = channel(&deps);
+    assert_eq!(channel.channel.amount_sent, Uint128::new(100));
+    assert_eq!(channel.channel.amount_received, Uint128::new(40));
+    assert_eq!(channel.net_outflow, Int256::from(60));
+}
+
+#[test]
+fn failed_receive_is_acked_as_error_without_accounting() {
+    let mut deps = setup();
+    connect(&mut deps);
+
+    let recv = mock_ibc_packet_recv(CHANNEL, &b"not a packet".to_vec()).unwrap();
+    let res = ibc_packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+    assert!(res.messages.is_empty());
+    assert!(matches!(
+        from_json(&res.acknowledgement).unwrap(),
+        BridgeAck::Error(_)
+    ));
+    assert_eq!(channel(&deps).channel.amount_received, Uint128::zero());
+}
+
+#[test]
+fn error_ack_remints_to_sender() {
+    let mut deps = setup();
+    connect(&mut deps);
+
+    let (_, packet) = transfer(&mut deps, "sender", 100);
+    let ack = IbcAcknowledgement::encode_json(&BridgeAck::Error("failed".to_owned())).unwrap();
+    let msg = mock_ibc_packet_ack(CHANNEL, &packet, ack).unwrap();
+    let res = ibc_packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+    assert_eq!(
+        complete_mint(&mut deps, &res.messages[0]),
+        ("sender".to_owned(), Uint128::new(100))
+    );
+
+    let channel = channel(&deps);
+    assert_eq!(channel.channel.amount_sent, Uint128::zero());
+    assert_eq!(channel.net_outflow, Int256::zero());
+}
+
+#[test]
+fn success_ack_keeps_transfer() {
+    let mut deps = setup();
+    connect(&mut deps);
+
+    let (_, packet) = transfer(&mut deps, "sender", 100);
+    let ack = IbcAcknowledgement::encode_json(&BridgeAck::Result(b"1".into())).unwrap();
+    let msg = mock_ibc_packet_ack(CHANNEL, &packet, ack).unwrap();
+    let res = ibc_packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(channel(&deps).channel.amount_sent, Uint128::new(100));
+}
+
+#[test]
+fn timeout_remints_to_sender() {
+    let mut deps = setup();
+    connect(&mut deps);
+
+    let (_, first) = transfer(&mut deps, "sender", 100);
+    transfer(&mut deps, "other", 30);
+
+    let msg = mock_ibc_packet_timeout(CHANNEL, &first).unwrap();
+    let res = ibc_packet_timeout(deps.as_mut(), mock_env(), msg).unwrap();
+    assert_eq!(
+        complete_mint(&mut deps, &res.messages[0]),
+        ("sender".to_owned(), Uint128::new(100))
+    );
+
+    // Only the transfer still in flight remains on the books
+    let channel = channel(&deps);
+    assert_eq!(channel.channel.amount_sent, Uint128::new(30));
+    assert_eq!(channel.net_outflow, Int256::from(30));
+}
+
+#[test]
+fn receive_over_max_supply_is_acked_as_error() {
+    let mut deps = setup();
+    connect(&mut deps);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("manager", &[]),
+        ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(50)),
+        },
+    )
+    .unwrap();
+
+    let inbound = BridgePacket {
+        sender: "remote".to_owned(),
+        recipient: "recipient".to_owned(),
+        amount: Uint128::new(60),
+    };
+    let recv = mock_ibc_packet_recv(CHANNEL, &inbound).unwrap();
+    let res = ibc_packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+    assert!(res.messages.is_empty());
+    match from_json(&res.acknowledgement).unwrap() {
+        BridgeAck::Error(e) => assert!(e.contains("exceeds the remaining supply")),
+        ack => panic!("expected an error ack, got {:?}", ack),
+    }
+    assert_eq!(channel(&deps).channel.amount_received, Uint128::zero());
+}
@@ -0,0 +1,278 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Binary, Decimal256, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdResult, Uint128,
+};
+use cw_contract::msg::{
+    CdpPositionResponse, ExecuteMsg, OraclePriceResponse, OracleQueryMsg, QueryMsg,
+};
+use cw_multi_test::{error::AnyResult, AppResponse, ContractWrapper, Executor};
+use cw_storage_plus::Map;
+
+const COLLATERAL_DENOM: &str = "uatom";
+
+/// Mock oracle pricing each denom at whatever was last set
+const PRICES: Map<&str, Decimal256> = Map::new("prices");
+
+#[cw_serde]
+struct SetPrice {
+    denom: String,
+    price: Decimal256,
+}
+
+fn oracle_execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: SetPrice,
+) -> StdResult<Response> {
+    PRICES.save(deps.storage, &msg.denom, &msg.price)?;
+    Ok(Response::new())
+}
+
+fn oracle_instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn oracle_query(
+    deps: Deps,
+    _env: Env,
+    msg: OracleQueryMsg,
+) -> StdResult<Binary> {
+    match msg {
+        OracleQueryMsg::Price { denom } => to_json_binary(&OraclePriceResponse {
+            price: PRICES.load(deps.storage, &denom)?,
+        }),
+    }
+}
+
+fn set_price(
+    suite: &mut Suite,
+    oracle: &Addr,
+    price: u64,
+) {
+    let manager = suite.manager.to_owned();
+    suite
+        .app
+        .execute_contract(
+            manager,
+            oracle.to_owned(),
+            &SetPrice {
+                denom: COLLATERAL_DENOM.to_owned(),
+                price: Decimal256::from_atomics(price, 0).unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+}
+
+fn configure_cdp(
+    suite: &mut Suite,
+    oracle: &Addr,
+    liquidation_ratio: Decimal256,
+    liquidation_bonus: Decimal256,
+) -> AnyResult<AppResponse> {
+    suite.execute_as_manager(ExecuteMsg::ConfigureCdp {
+        oracle: oracle.to_owned(),
+        collateral_denoms: vec![COLLATERAL_DENOM.to_owned()],
+        collateral_ratio: Decimal256::percent(150),
+        liquidation_ratio,
+        liquidation_bonus,
+    })
+}
+
+fn setup() -> (Suite, Addr) {
+    let mut suite = Suite::new();
+    let code_id = suite.app.store_code(Box::new(ContractWrapper::new(
+        oracle_execute,
+        oracle_instantiate,
+        oracle_query,
+    )));
+    let manager = suite.manager.to_owned();
+    let oracle = suite
+        .app
+        .instantiate_contract(code_id, manager, &Empty {}, &[], "oracle", None)
+        .unwrap();
+    set_price(&mut suite, &oracle, 10);
+    configure_cdp(
+        &mut suite,
+        &oracle,
+        Decimal256::percent(120),
+        Decimal256::percent(10),
+    )
+    .unwrap();
+    (suite, oracle)
+}
+
+fn position(
+    suite: &Suite,
+    owner: &Addr,
+) -> CdpPositionResponse {
+    suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.contract,
+            &QueryMsg::CdpPosition {
+                address: owner.to_owned(),
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn configure_rejects_unsafe_parameters() {
+    let (mut suite, oracle) = setup();
+    configure_cdp(
+        &mut suite,
+        &oracle,
+        Decimal256::one(),
+        Decimal256::percent(10),
+    )
+    .unwrap_err();
+    configure_cdp(
+        &mut suite,
+        &oracle,
+        Decimal256::percent(120),
+        Decimal256::one(),
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn open_borrow_and_repay() {
+    let (mut suite, _) = setup();
+    let alice = suite.addr("alice");
+    suite.fund(&alice, coins(100, COLLATERAL_DENOM));
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::DepositCollateral {},
+            &coins(100, COLLATERAL_DENOM),
+        )
+        .unwrap();
+    assert_eq!(
+        position(&suite, &alice).collateral_value,
+        Uint128::new(1_000)
+    );
+
+    // 1_000 of collateral value supports up to 666 at a 150% ratio
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::Borrow {
+                amount: Uint128::new(700),
+            },
+            &[],
+        )
+        .unwrap_err();
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::Borrow {
+                amount: Uint128::new(600),
+            },
+            &[],
+        )
+        .unwrap();
+    let denom = suite.denom.to_owned();
+    assert_eq!(suite.balance(&alice, &denom), Uint128::new(600));
+
+    // Collateral can't be withdrawn below the ratio
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::WithdrawCollateral {
+                collateral: coins(20, COLLATERAL_DENOM),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    suite
+        .execute(&alice, ExecuteMsg::Repay {}, &coins(600, &denom))
+        .unwrap();
+    assert_eq!(position(&suite, &alice).position.debt, Uint128::zero());
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::WithdrawCollateral {
+                collateral: coins(100, COLLATERAL_DENOM),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(suite.balance(&alice, COLLATERAL_DENOM), Uint128::new(100));
+}
+
+#[test]
+fn liquidate_after_price_drop() {
+    let (mut suite, oracle) = setup();
+    let alice = suite.addr("alice");
+    suite.fund(&alice, coins(100, COLLATERAL_DENOM));
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::DepositCollateral {},
+            &coins(100, COLLATERAL_DENOM),
+        )
+        .unwrap();
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::Borrow {
+                amount: Uint128::new(500),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let liquidator = suite.addr("liquidator");
+    suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient: liquidator.to_owned(),
+            amount: Uint128::new(300),
+        })
+        .unwrap();
+    let denom = suite.denom.to_owned();
+    suite
+        .execute(
+            &liquidator,
+            ExecuteMsg::Liquidate {
+                owner: alice.to_owned(),
+            },
+            &coins(300, &denom),
+        )
+        .unwrap_err();
+
+    // At half the price the position is at 100%, below the 120% threshold
+    set_price(&mut suite, &oracle, 5);
+    assert!(position(&suite, &alice).is_liquidatable);
+    suite
+        .execute(
+            &liquidator,
+            ExecuteMsg::Liquidate {
+                owner: alice.to_owned(),
+            },
+            &coins(300, &denom),
+        )
+        .unwrap();
+
+    // 300 repaid plus the 10% bonus is 330 of value, or 66 uatom
+    assert_eq!(
+        suite.balance(&liquidator, COLLATERAL_DENOM),
+        Uint128::new(66)
+    );
+    assert_eq!(suite.balance(&liquidator, &denom), Uint128::zero());
+    let resp = position(&suite, &alice);
+    assert_eq!(resp.position.debt, Uint128::new(200));
+    assert_eq!(resp.position.collateral, coins(34, COLLATERAL_DENOM));
+}
@@ -0,0 +1,364 @@
+//! Shared cw-multi-test harness. Tokenfactory messages are handled by a
+//! stargate module that mints and burns through the bank module.
+
+#![allow(dead_code)]
+
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_env, MockStorage},
+    to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin, CustomQuery, Decimal, Empty,
+    Event, Querier, Storage, Uint128, Validator,
+};
+use cw_contract::{
+    contract::{execute, instantiate, query, reply, sudo},
+    msg::{ExecuteMsg, InstantiateMsg, NewDenomMetadata},
+    tf::cosmos::{
+        denom::{MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, MsgSetDenomMetadata},
+        staking::{
+            QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
+            UnbondingDelegation, UnbondingDelegationEntry,
+        },
+        transfer::MsgTransfer,
+    },
+};
+use cw_multi_test::{
+    addons::{MockAddressGenerator, MockApiBech32},
+    error::{bail, AnyResult},
+    App, AppBuilder, AppResponse, BankKeeper, BankSudo, Contract, ContractWrapper, CosmosRouter,
+    DistributionKeeper, Executor, FailingModule, GovFailingModule, IbcFailingModule, StakeKeeper,
+    StakingInfo, Stargate, WasmKeeper,
+};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{collections::VecDeque, fmt::Debug};
+
+pub const BECH32_PREFIX: &str = "osmo";
+pub const BOND_DENOM: &str = "ustake";
+pub const UNBONDING_TIME: u64 = 60;
+pub const VALIDATORS: [&str; 2] = ["validator1", "validator2"];
+/// Holds tokens sent by `MsgTransfer` until the test acks or refunds them
+pub const IBC_ESCROW: &str = "ibc_escrow";
+
+/// Storage key of the last packet sequence sent by `MsgTransfer`
+const IBC_SEQUENCE_KEY: &[u8] = b"mock_ibc_sequence";
+
+pub type TestApp = App<
+    BankKeeper,
+    MockApiBech32,
+    MockStorage,
+    FailingModule<Empty, Empty, Empty>,
+    WasmKeeper<Empty, Empty>,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcFailingModule,
+    GovFailingModule,
+    TokenFactoryStargate,
+>;
+
+/// Handles the messages the contract sends to the CosmWasm tokenfactory,
+/// which is the factory used on chains without a dedicated one, escrows
+/// ICS-20 transfers and answers unbonding queries from the staking module's
+/// queue
+pub struct TokenFactoryStargate;
+
+/// Entry of the staking module's unbonding queue, as stored by multi-test
+#[derive(Deserialize)]
+struct Unbonding {
+    delegator: Addr,
+    validator: Addr,
+    amount: Uint128,
+}
+
+/// Key of the unbonding queue in the length-prefixed staking namespace
+fn unbonding_queue_key() -> Vec<u8> {
+    let namespace = b"staking";
+    let mut key = (namespace.len() as u16).to_be_bytes().to_vec();
+    key.extend_from_slice(namespace);
+    key.extend_from_slice(b"unbonding_queue");
+    key
+}
+
+impl Stargate for TokenFactoryStargate {
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        type_url: String,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match type_url.as_str() {
+            MsgMint::TYPE_URL => {
+                let msg = MsgMint::try_from(value)?;
+                let amount = to_coin(msg.amount)?;
+                router.sudo(
+                    api,
+                    storage,
+                    block,
+                    BankSudo::Mint {
+                        to_address: sender.to_string(),
+                        amount: vec![amount],
+                    }
+                    .into(),
+                )
+            },
+            MsgBurn::TYPE_URL => {
+                let msg = MsgBurn::try_from(value)?;
+                let amount = to_coin(msg.amount)?;
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    sender,
+                    BankMsg::Burn {
+                        amount: vec![amount],
+                    }
+                    .into(),
+                )
+            },
+            MsgTransfer::TYPE_URL => {
+                let msg = MsgTransfer::try_from(value)?;
+                let amount = to_coin(msg.token)?;
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    sender,
+                    BankMsg::Send {
+                        to_address: MockApiBech32::new(BECH32_PREFIX)
+                            .addr_make(IBC_ESCROW)
+                            .to_string(),
+                        amount: vec![amount],
+                    }
+                    .into(),
+                )?;
+
+                let sequence = match storage.get(IBC_SEQUENCE_KEY) {
+                    Some(raw) => from_json::<u64>(raw)? + 1,
+                    None => 1,
+                };
+                storage.set(IBC_SEQUENCE_KEY, &to_json_binary(&sequence)?);
+                Ok(AppResponse {
+                    events: vec![Event::new("send_packet")
+                        .add_attribute("packet_src_channel", msg.source_channel)
+                        .add_attribute("packet_sequence", sequence.to_string())],
+                    data: None,
+                })
+            },
+            MsgCreateDenom::TYPE_URL | MsgSetDenomMetadata::TYPE_URL | MsgChangeAdmin::TYPE_URL => {
+                Ok(AppResponse::default())
+            },
+            _ => bail!("unexpected stargate message {}", type_url),
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        path: String,
+        data: Binary,
+    ) -> AnyResult<Binary> {
+        if path != "/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations" {
+            bail!("unexpected stargate query {}", path);
+        }
+        let request = QueryDelegatorUnbondingDelegationsRequest::try_from(data)?;
+        let queue: VecDeque<Unbonding> = match storage.get(&unbonding_queue_key()) {
+            Some(raw) => from_json(raw)?,
+            None => VecDeque::new(),
+        };
+        let unbonding_responses = queue
+            .into_iter()
+            .filter(|u| u.delegator.as_str() == request.delegator_addr)
+            .map(|u| UnbondingDelegation {
+                delegator_address: u.delegator.to_string(),
+                validator_address: u.validator.to_string(),
+                entries: vec![UnbondingDelegationEntry {
+                    initial_balance: u.amount.to_string(),
+                    balance: u.amount.to_string(),
+                }],
+            })
+            .collect();
+        Ok(to_json_binary(
+            &QueryDelegatorUnbondingDelegationsResponse {
+                unbonding_responses,
+            },
+        )?)
+    }
+}
+
+fn to_coin(coin: Option<cw_contract::tf::cosmos::denom::Coin>) -> AnyResult<Coin> {
+    match coin {
+        Some(coin) => Ok(Coin::new(coin.amount.parse::<u128>()?, coin.denom)),
+        None => bail!("missing coin"),
+    }
+}
+
+pub fn contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(execute, instantiate, query)
+            .with_reply(reply)
+            .with_sudo(sudo),
+    )
+}
+
+pub struct Suite {
+    pub app: TestApp,
+    pub contract: Addr,
+    pub denom: String,
+    pub manager: Addr,
+}
+
+impl Suite {
+    pub fn new() -> Self {
+        let mut app = AppBuilder::new()
+            .with_api(MockApiBech32::new(BECH32_PREFIX))
+            .with_wasm(WasmKeeper::new().with_address_generator(MockAddressGenerator))
+            .with_stargate(TokenFactoryStargate)
+            .build(|router, api, storage| {
+                router
+                    .staking
+                    .setup(
+                        storage,
+                        StakingInfo {
+                            bonded_denom: BOND_DENOM.to_owned(),
+                            unbonding_time: UNBONDING_TIME,
+                            apr: Decimal::percent(10),
+                        },
+                    )
+                    .unwrap();
+                for validator in VALIDATORS {
+                    router
+                        .staking
+                        .add_validator(
+                            api,
+                            storage,
+                            &mock_env().block,
+                            Validator {
+                                address: MockApiBech32::new(BECH32_PREFIX)
+                                    .addr_make(validator)
+                                    .to_string(),
+                                commission: Decimal::zero(),
+                                max_commission: Decimal::one(),
+                                max_change_rate: Decimal::one(),
+                            },
+                        )
+                        .unwrap();
+                }
+            });
+
+        let manager = app.api().addr_make("manager");
+        let code_id = app.store_code(contract());
+        let contract = app
+            .instantiate_contract(
+                code_id,
+                manager.to_owned(),
+                &InstantiateMsg {
+                    manager: None,
+                    factory: None,
+                    initial_balances: None,
+                    metadata: NewDenomMetadata {
+                        symbol: "TKN".to_owned(),
+                        decimals: 6,
+                        name: "Token".to_owned(),
+                        description: None,
+                        uri: None,
+                    },
+                    mint_fee: None,
+                },
+                &[],
+                "token",
+                None,
+            )
+            .unwrap();
+        let denom = format!("factory/{}/tkn", contract);
+
+        Self {
+            app,
+            contract,
+            denom,
+            manager,
+        }
+    }
+
+    pub fn addr(
+        &self,
+        name: &str,
+    ) -> Addr {
+        self.app.api().addr_make(name)
+    }
+
+    pub fn validator(
+        &self,
+        index: usize,
+    ) -> String {
+        self.addr(VALIDATORS[index]).to_string()
+    }
+
+    pub fn fund(
+        &mut self,
+        address: &Addr,
+        coins: Vec<Coin>,
+    ) {
+        self.app
+            .sudo(
+                BankSudo::Mint {
+                    to_address: address.to_string(),
+                    amount: coins,
+                }
+                .into(),
+            )
+            .unwrap();
+    }
+
+    pub fn balance(
+        &self,
+        address: &Addr,
+        denom: &str,
+    ) -> Uint128 {
+        self.app
+            .wrap()
+            .query_balance(address, denom)
+            .unwrap()
+            .amount
+    }
+
+    pub fn execute(
+        &mut self,
+        sender: &Addr,
+        msg: ExecuteMsg,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        self.app
+            .execute_contract(sender.to_owned(), self.contract.to_owned(), &msg, funds)
+    }
+
+    pub fn execute_as_manager(
+        &mut self,
+        msg: ExecuteMsg,
+    ) -> AnyResult<AppResponse> {
+        let manager = self.manager.to_owned();
+        self.execute(&manager, msg, &[])
+    }
+
+    pub fn advance_seconds(
+        &mut self,
+        seconds: u64,
+    ) {
+        self.app.update_block(|block| {
+            block.time = block.time.plus_seconds(seconds);
+            block.height += seconds / 5;
+        });
+        // The staking queue is processed against the block being replaced,
+        // so step once more to pay out undelegations maturing at this time
+        self.app.update_block(|_| {});
+    }
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_std::Uint128;
+use cw_contract::msg::{ExecuteMsg, MintParams};
+
+#[test]
+fn appended_balances_are_minted_and_sent() {
+    let mut suite = Suite::new();
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+    suite
+        .execute_as_manager(ExecuteMsg::AppendInitialBalances {
+            balances: vec![
+                MintParams {
+                    address: alice.to_owned(),
+                    amount: Uint128::new(100),
+                },
+                MintParams {
+                    address: bob.to_owned(),
+                    amount: Uint128::new(200),
+                },
+            ],
+        })
+        .unwrap();
+
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(100));
+    assert_eq!(suite.balance(&bob, &suite.denom), Uint128::new(200));
+}
+
+#[test]
+fn zero_and_duplicate_balances_are_rejected() {
+    let mut suite = Suite::new();
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+
+    let err = suite
+        .execute_as_manager(ExecuteMsg::AppendInitialBalances {
+            balances: vec![
+                MintParams {
+                    address: alice.to_owned(),
+                    amount: Uint128::new(100),
+                },
+                MintParams {
+                    address: bob.to_owned(),
+                    amount: Uint128::zero(),
+                },
+            ],
+        })
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("must be non-zero"));
+
+    let err = suite
+        .execute_as_manager(ExecuteMsg::AppendInitialBalances {
+            balances: vec![
+                MintParams {
+                    address: alice.to_owned(),
+                    amount: Uint128::new(100),
+                },
+                MintParams {
+                    address: alice.to_owned(),
+                    amount: Uint128::new(50),
+                },
+            ],
+        })
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("duplicate"));
+}
@@ -0,0 +1,235 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_std::{Addr, Event, Uint128};
+use cw20::MinterResponse;
+use cw_contract::{
+    msg::{ConfigResponse, ExecuteMsg, QueryMsg},
+    state::models::{EmissionSchedule, MintFee},
+};
+
+fn add_linear_emission(
+    suite: &mut Suite,
+    recipient: &Addr,
+    rate_per_second: u128,
+) {
+    suite
+        .execute_as_manager(ExecuteMsg::AddEmission {
+            recipient: recipient.to_owned(),
+            start: None,
+            schedule: EmissionSchedule::Linear {
+                rate_per_second: Uint128::new(rate_per_second),
+            },
+            total: None,
+        })
+        .unwrap();
+}
+
+#[test]
+fn emit_pages_through_streams() {
+    let mut suite = Suite::new();
+    let recipients: Vec<Addr> = (0..3)
+        .map(|i| suite.addr(&format!("recipient{}", i)))
+        .collect();
+    for recipient in &recipients {
+        add_linear_emission(&mut suite, recipient, 10);
+    }
+    suite.advance_seconds(10);
+
+    let anyone = suite.addr("anyone");
+    suite
+        .execute(&anyone, ExecuteMsg::Emit { limit: Some(2) }, &[])
+        .unwrap();
+    assert_eq!(
+        suite.balance(&recipients[0], &suite.denom),
+        Uint128::new(100)
+    );
+    assert_eq!(
+        suite.balance(&recipients[1], &suite.denom),
+        Uint128::new(100)
+    );
+    assert_eq!(suite.balance(&recipients[2], &suite.denom), Uint128::zero());
+
+    // The next call resumes after the last stream visited
+    suite
+        .execute(&anyone, ExecuteMsg::Emit { limit: Some(2) }, &[])
+        .unwrap();
+    assert_eq!(
+        suite.balance(&recipients[0], &suite.denom),
+        Uint128::new(100)
+    );
+    assert_eq!(
+        suite.balance(&recipients[2], &suite.denom),
+        Uint128::new(100)
+    );
+}
+
+#[test]
+fn emit_stops_at_max_supply() {
+    let mut suite = Suite::new();
+    let recipient = suite.addr("recipient");
+    let fee_recipient = suite.addr("fee_recipient");
+    suite
+        .execute_as_manager(ExecuteMsg::SetMintFee {
+            mint_fee: Some(MintFee {
+                bps: 1_000,
+                recipient: fee_recipient.to_owned(),
+            }),
+        })
+        .unwrap();
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(1_000)),
+        })
+        .unwrap();
+    add_linear_emission(&mut suite, &recipient, 100);
+    suite.advance_seconds(20);
+
+    let anyone = suite.addr("anyone");
+    suite
+        .execute(&anyone, ExecuteMsg::Emit { limit: None }, &[])
+        .unwrap();
+
+    // 909 plus its 90 fee is the most that fits under the cap
+    let emitted = suite.balance(&recipient, &suite.denom);
+    let fees = suite.balance(&fee_recipient, &suite.denom);
+    assert_eq!(emitted, Uint128::new(909));
+    assert_eq!(fees, Uint128::new(90));
+
+    let err = suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient: recipient.to_owned(),
+            amount: Uint128::new(10),
+        })
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("exceeds the remaining supply"));
+
+    // Raising the cap lets the stream emit what it held back
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply { max_supply: None })
+        .unwrap();
+    suite
+        .execute(&anyone, ExecuteMsg::Emit { limit: None }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&recipient, &suite.denom), Uint128::new(2_000));
+
+    let config: ConfigResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.0.max_supply, None);
+}
+
+#[test]
+fn max_supply_cannot_go_below_minted() {
+    let mut suite = Suite::new();
+    let recipient = suite.addr("recipient");
+    suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient,
+            amount: Uint128::new(500),
+        })
+        .unwrap();
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(499)),
+        })
+        .unwrap_err();
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(500)),
+        })
+        .unwrap();
+}
+
+#[test]
+fn burns_free_up_max_supply() {
+    let mut suite = Suite::new();
+    let contract = suite.contract.to_owned();
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(1_000)),
+        })
+        .unwrap();
+    suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient: contract.to_owned(),
+            amount: Uint128::new(1_000),
+        })
+        .unwrap();
+    suite
+        .execute_as_manager(ExecuteMsg::Burn {
+            amount: Uint128::new(400),
+        })
+        .unwrap();
+
+    suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient: contract.to_owned(),
+            amount: Uint128::new(400),
+        })
+        .unwrap();
+    assert_eq!(suite.balance(&contract, &suite.denom), Uint128::new(1_000));
+}
+
+#[test]
+fn due_mints_over_max_supply_are_deferred() {
+    let mut suite = Suite::new();
+    let recipient = suite.addr("recipient");
+    let now = suite.app.block_info().time;
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(1_000)),
+        })
+        .unwrap();
+    for _ in 0..2 {
+        suite
+            .execute_as_manager(ExecuteMsg::ScheduleMint {
+                recipient: recipient.to_owned(),
+                amount: Uint128::new(600),
+                not_before: now,
+            })
+            .unwrap();
+    }
+
+    let anyone = suite.addr("anyone");
+    let res = suite
+        .execute(&anyone, ExecuteMsg::ExecuteDueMints { limit: None }, &[])
+        .unwrap();
+    assert!(res.has_event(&Event::new("wasm").add_attribute("deferred", "200")));
+    assert_eq!(suite.balance(&recipient, &suite.denom), Uint128::new(1_000));
+
+    // Nothing fits, but the call still succeeds rather than jamming
+    suite
+        .execute(&anyone, ExecuteMsg::ExecuteDueMints { limit: None }, &[])
+        .unwrap();
+
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply { max_supply: None })
+        .unwrap();
+    suite
+        .execute(&anyone, ExecuteMsg::ExecuteDueMints { limit: None }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&recipient, &suite.denom), Uint128::new(1_200));
+}
+
+#[test]
+fn minter_cap_reports_max_supply() {
+    let mut suite = Suite::new();
+    suite
+        .execute_as_manager(ExecuteMsg::SetMaxSupply {
+            max_supply: Some(Uint128::new(1_000)),
+        })
+        .unwrap();
+    let minter: MinterResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::Minter {})
+        .unwrap();
+    assert_eq!(minter.minter, suite.manager.to_string());
+    assert_eq!(minter.cap, Some(Uint128::new(1_000)));
+}
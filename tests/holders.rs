@@ -0,0 +1,286 @@
+//! Holder tracking needs a tokenfactory with BeforeSend hooks, which the
+//! multi-test harness does not provide, so these drive the entry points
+//! directly against mock dependencies.
+
+use cosmwasm_std::{
+    coin, coins, from_json,
+    testing::{mock_env, mock_info, MockQuerier, MockStorage},
+    Addr, Coin, Env, OwnedDeps, Response,
+};
+use cw_contract::{
+    contract::{execute, instantiate, query, sudo},
+    error::ContractError,
+    msg::{
+        ExecuteMsg, HolderCountResponse, HoldersResponse, InstantiateMsg, NewDenomMetadata,
+        QueryMsg, SudoMsg,
+    },
+    tf::tokenfactory::TokenFactoryType,
+};
+use cw_multi_test::addons::MockApiBech32;
+use std::marker::PhantomData;
+
+type Deps = OwnedDeps<MockStorage, MockApiBech32, MockQuerier>;
+
+/// The tokenfactory module address is derived as a 20 byte account, which
+/// needs a bech32 api
+fn api() -> MockApiBech32 {
+    MockApiBech32::new("osmo")
+}
+
+fn addr(name: &str) -> String {
+    api().addr_make(name).to_string()
+}
+
+fn env() -> Env {
+    let mut env = mock_env();
+    env.contract.address = api().addr_make("contract");
+    env
+}
+
+fn denom() -> String {
+    format!("factory/{}/tkn", env().contract.address)
+}
+
+/// Instantiate with holder tracking enabled, returning the tokenfactory
+/// module address
+fn setup() -> (Deps, String) {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: api(),
+        querier: MockQuerier::default(),
+        custom_query_type: PhantomData,
+    };
+    instantiate(
+        deps.as_mut(),
+        env(),
+        mock_info(&addr("manager"), &[]),
+        InstantiateMsg {
+            manager: None,
+            factory: Some(TokenFactoryType::Osmosis),
+            initial_balances: None,
+            metadata: NewDenomMetadata {
+                symbol: "TKN".to_owned(),
+                decimals: 6,
+                name: "Token".to_owned(),
+                description: None,
+                uri: None,
+            },
+            mint_fee: None,
+        },
+    )
+    .unwrap();
+    let res = execute(
+        deps.as_mut(),
+        env(),
+        mock_info(&addr("manager"), &[]),
+        ExecuteMsg::EnableHolderTracking {},
+    )
+    .unwrap();
+    let module_address = res
+        .attributes
+        .iter()
+        .find(|a| a.key == "module_address")
+        .unwrap()
+        .value
+        .to_owned();
+    (deps, module_address)
+}
+
+fn track(
+    deps: &mut Deps,
+    from: &str,
+    to: &str,
+    amount: Coin,
+) -> Response {
+    sudo(
+        deps.as_mut(),
+        env(),
+        SudoMsg::TrackBeforeSend {
+            from: from.to_owned(),
+            to: to.to_owned(),
+            amount,
+        },
+    )
+    .unwrap()
+}
+
+fn transfer(
+    deps: &mut Deps,
+    from: &str,
+    to: &str,
+    amount: u128,
+) -> Response {
+    track(deps, from, to, coin(amount, denom()))
+}
+
+fn reconcile(
+    deps: &mut Deps,
+    address: &str,
+) -> Result<Response, ContractError> {
+    execute(
+        deps.as_mut(),
+        env(),
+        mock_info(&addr("anyone"), &[]),
+        ExecuteMsg::ReconcileHolder {
+            address: Addr::unchecked(address),
+        },
+    )
+}
+
+fn holder_count(deps: &Deps) -> u64 {
+    let res = query(deps.as_ref(), env(), QueryMsg::HolderCount {}).unwrap();
+    from_json::<HolderCountResponse>(res).unwrap().count
+}
+
+fn holders(deps: &Deps) -> Vec<(String, u128)> {
+    let res = query(
+        deps.as_ref(),
+        env(),
+        QueryMsg::Holders {
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    from_json::<HoldersResponse>(res)
+        .unwrap()
+        .holders
+        .into_iter()
+        .map(|h| (h.address.to_string(), h.balance.u128()))
+        .collect()
+}
+
+/// Holders are listed by address, and bech32 addresses sort independently of
+/// the names they are made from
+fn sorted(mut holders: Vec<(String, u128)>) -> Vec<(String, u128)> {
+    holders.sort();
+    holders
+}
+
+fn top_holders(
+    deps: &Deps,
+    limit: Option<u32>,
+) -> Vec<(String, u128)> {
+    let res = query(deps.as_ref(), env(), QueryMsg::TopHolders { limit }).unwrap();
+    from_json::<HoldersResponse>(res)
+        .unwrap()
+        .holders
+        .into_iter()
+        .map(|h| (h.address.to_string(), h.balance.u128()))
+        .collect()
+}
+
+#[test]
+fn mints_transfers_and_burns_are_mirrored() {
+    let (mut deps, module) = setup();
+    let contract = env().contract.address.to_string();
+    let alice = addr("alice");
+    let bob = addr("bob");
+
+    // Minted from the module to this contract, then sent on
+    transfer(&mut deps, &module, &contract, 100);
+    transfer(&mut deps, &contract, &alice, 100);
+    transfer(&mut deps, &alice, &bob, 30);
+    // Sent back to this contract, then burned through the module
+    transfer(&mut deps, &alice, &contract, 20);
+    transfer(&mut deps, &contract, &module, 20);
+    // Other denoms and zero amounts are ignored
+    track(&mut deps, &alice, &bob, coin(5, "uother"));
+    transfer(&mut deps, &alice, &bob, 0);
+
+    assert_eq!(holders(&deps), sorted(vec![(alice, 50), (bob, 30)]));
+    assert_eq!(holder_count(&deps), 2);
+}
+
+#[test]
+fn holder_count_follows_balances_to_and_from_zero() {
+    let (mut deps, _) = setup();
+    let contract = env().contract.address.to_string();
+    let alice = addr("alice");
+    let bob = addr("bob");
+    assert_eq!(holder_count(&deps), 0);
+
+    transfer(&mut deps, &contract, &alice, 100);
+    assert_eq!(holder_count(&deps), 1);
+    transfer(&mut deps, &contract, &alice, 100);
+    assert_eq!(holder_count(&deps), 1);
+    transfer(&mut deps, &alice, &bob, 50);
+    assert_eq!(holder_count(&deps), 2);
+    transfer(&mut deps, &alice, &bob, 150);
+    assert_eq!(holder_count(&deps), 1);
+    assert_eq!(holders(&deps), vec![(bob.to_owned(), 200)]);
+    transfer(&mut deps, &bob, &contract, 200);
+    assert_eq!(holder_count(&deps), 0);
+    assert_eq!(holders(&deps), vec![]);
+}
+
+#[test]
+fn top_holders_are_ordered_by_balance() {
+    let (mut deps, _) = setup();
+    let contract = env().contract.address.to_string();
+    let alice = addr("alice");
+    let bob = addr("bob");
+    let carol = addr("carol");
+    transfer(&mut deps, &contract, &alice, 10);
+    transfer(&mut deps, &contract, &bob, 300);
+    transfer(&mut deps, &contract, &carol, 50);
+
+    assert_eq!(
+        top_holders(&deps, None),
+        vec![
+            (bob.to_owned(), 300),
+            (carol.to_owned(), 50),
+            (alice.to_owned(), 10),
+        ]
+    );
+
+    // Moving tokens reorders the index
+    transfer(&mut deps, &bob, &alice, 290);
+    assert_eq!(top_holders(&deps, Some(2)), vec![(alice, 300), (carol, 50)]);
+}
+
+#[test]
+fn reconcile_holder_syncs_balances_held_before_tracking() {
+    let (mut deps, module) = setup();
+    let alice = addr("alice");
+    deps.querier.update_balance(&alice, coins(500, denom()));
+
+    let res = reconcile(&mut deps, &alice).unwrap();
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "previous" && a.value == "0"));
+    assert_eq!(holders(&deps), vec![(alice.to_owned(), 500)]);
+    assert_eq!(holder_count(&deps), 1);
+
+    deps.querier.update_balance(&alice, vec![]);
+    reconcile(&mut deps, &alice).unwrap();
+    assert_eq!(holders(&deps), vec![]);
+    assert_eq!(holder_count(&deps), 0);
+
+    let err = reconcile(&mut deps, &module).unwrap_err();
+    assert!(err.to_string().contains("is not tracked as a holder"));
+    let err = reconcile(&mut deps, env().contract.address.as_str()).unwrap_err();
+    assert!(err.to_string().contains("is not tracked as a holder"));
+}
+
+#[test]
+fn under_tracked_sender_is_resynced_from_bank() {
+    let (mut deps, _) = setup();
+    let contract = env().contract.address.to_string();
+    let alice = addr("alice");
+    let bob = addr("bob");
+    transfer(&mut deps, &contract, &alice, 100);
+    // 400 more was held before tracking was enabled. The bank has not moved
+    // the tokens being sent when the hook runs.
+    deps.querier.update_balance(&alice, coins(500, denom()));
+
+    let res = transfer(&mut deps, &alice, &bob, 200);
+
+    assert!(res
+        .attributes
+        .iter()
+        .any(|a| a.key == "action" && a.value == "resync_holder"));
+    assert_eq!(holders(&deps), sorted(vec![(alice, 300), (bob, 200)]));
+    assert_eq!(holder_count(&deps), 2);
+}
@@ -0,0 +1,255 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    testing::MockStorage, to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo,
+    ReplyOn, Response, StdError, StdResult, Uint128,
+};
+use cw_contract::{
+    execute::hooks::build_mint_hook_submsgs,
+    msg::{DistributionStatusResponse, ExecuteMsg, MintParams, QueryMsg, TokenFactoryHookMsg},
+    state::{
+        models::Hook,
+        storage::{FULL_DENOM, HOOKS},
+        HOOK_GAS_LIMIT, HOOK_REPLY_ID,
+    },
+};
+use cw_multi_test::{AppResponse, ContractWrapper, Executor};
+use cw_storage_plus::Item;
+
+/// Whether the mock hook rejects every msg it is sent
+const FAIL: Item<bool> = Item::new("fail");
+/// Msgs received by the mock hook, in order
+const RECEIVED: Item<Vec<TokenFactoryHookMsg>> = Item::new("received");
+
+#[cw_serde]
+struct HookInstantiateMsg {
+    fail: bool,
+}
+
+fn hook_instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: HookInstantiateMsg,
+) -> StdResult<Response> {
+    FAIL.save(deps.storage, &msg.fail)?;
+    RECEIVED.save(deps.storage, &vec![])?;
+    Ok(Response::new())
+}
+
+fn hook_execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: TokenFactoryHookMsg,
+) -> StdResult<Response> {
+    if FAIL.load(deps.storage)? {
+        return Err(StdError::generic_err("hook rejected msg"));
+    }
+    RECEIVED.update(deps.storage, |mut received| -> StdResult<_> {
+        received.push(msg);
+        Ok(received)
+    })?;
+    Ok(Response::new())
+}
+
+fn hook_query(
+    deps: Deps,
+    _env: Env,
+    _msg: Empty,
+) -> StdResult<Binary> {
+    to_json_binary(&RECEIVED.load(deps.storage)?)
+}
+
+fn add_hook(
+    suite: &mut Suite,
+    fail: bool,
+    is_blocking: bool,
+) -> Addr {
+    let code_id = suite.app.store_code(Box::new(ContractWrapper::new(
+        hook_execute,
+        hook_instantiate,
+        hook_query,
+    )));
+    let manager = suite.manager.to_owned();
+    let hook = suite
+        .app
+        .instantiate_contract(
+            code_id,
+            manager,
+            &HookInstantiateMsg { fail },
+            &[],
+            "hook",
+            None,
+        )
+        .unwrap();
+    suite
+        .execute_as_manager(ExecuteMsg::AddHook {
+            contract: hook.to_owned(),
+            is_blocking: Some(is_blocking),
+        })
+        .unwrap();
+    hook
+}
+
+fn received(
+    suite: &Suite,
+    hook: &Addr,
+) -> Vec<TokenFactoryHookMsg> {
+    suite.app.wrap().query_wasm_smart(hook, &Empty {}).unwrap()
+}
+
+fn has_attr(
+    res: &AppResponse,
+    key: &str,
+    value: &str,
+) -> bool {
+    res.events.iter().any(|e| {
+        e.attributes
+            .iter()
+            .any(|a| a.key == key && a.value == value)
+    })
+}
+
+#[test]
+fn hooks_are_sent_mints_and_burns() {
+    let mut suite = Suite::new();
+    let hook = add_hook(&mut suite, false, false);
+    let contract = suite.contract.to_owned();
+    let denom = suite.denom.to_owned();
+
+    suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient: contract.to_owned(),
+            amount: Uint128::new(100),
+        })
+        .unwrap();
+    suite
+        .execute_as_manager(ExecuteMsg::Burn {
+            amount: Uint128::new(40),
+        })
+        .unwrap();
+
+    assert_eq!(
+        received(&suite, &hook),
+        vec![
+            TokenFactoryHookMsg::Minted {
+                recipient: contract.to_string(),
+                amount: Uint128::new(100),
+                denom: denom.to_owned(),
+            },
+            TokenFactoryHookMsg::Burned {
+                owner: contract.to_string(),
+                amount: Uint128::new(40),
+                denom,
+            },
+        ]
+    );
+}
+
+#[test]
+fn failed_non_blocking_hook_does_not_revert_mint() {
+    let mut suite = Suite::new();
+    let healthy = add_hook(&mut suite, false, false);
+    add_hook(&mut suite, true, false);
+    let alice = suite.addr("alice");
+
+    let res = suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient: alice.to_owned(),
+            amount: Uint128::new(100),
+        })
+        .unwrap();
+
+    assert!(has_attr(&res, "action", "hook_failed"));
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(100));
+    assert_eq!(received(&suite, &healthy).len(), 1);
+}
+
+#[test]
+fn failed_blocking_hook_reverts_mint() {
+    let mut suite = Suite::new();
+    add_hook(&mut suite, true, true);
+    let alice = suite.addr("alice");
+
+    let err = suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient: alice.to_owned(),
+            amount: Uint128::new(100),
+        })
+        .unwrap_err();
+
+    assert!(err.root_cause().to_string().contains("hook rejected msg"));
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::zero());
+}
+
+#[test]
+fn only_non_blocking_hooks_are_gas_limited() {
+    let mut storage = MockStorage::new();
+    FULL_DENOM
+        .save(&mut storage, &"factory/contract/tkn".to_owned())
+        .unwrap();
+    for (contract, is_blocking) in [("blocking", true), ("non_blocking", false)] {
+        let contract = Addr::unchecked(contract);
+        HOOKS
+            .save(
+                &mut storage,
+                &contract,
+                &Hook {
+                    contract: contract.to_owned(),
+                    is_blocking,
+                },
+            )
+            .unwrap();
+    }
+
+    let submsgs = build_mint_hook_submsgs(&storage, "recipient", Uint128::new(100)).unwrap();
+
+    assert_eq!(submsgs.len(), 2);
+    assert_eq!(submsgs[0].reply_on, ReplyOn::Never);
+    assert_eq!(submsgs[0].gas_limit, None);
+    assert_eq!(submsgs[1].reply_on, ReplyOn::Error);
+    assert_eq!(submsgs[1].id, HOOK_REPLY_ID);
+    assert_eq!(submsgs[1].gas_limit, Some(HOOK_GAS_LIMIT));
+}
+
+#[test]
+fn distribution_pages_shrink_with_hook_count() {
+    let mut suite = Suite::new();
+    let hooks: Vec<Addr> = (0..4).map(|_| add_hook(&mut suite, false, false)).collect();
+    let balances: Vec<MintParams> = (0..20)
+        .map(|i| MintParams {
+            address: suite.addr(&format!("holder{}", i)),
+            amount: Uint128::new(10),
+        })
+        .collect();
+
+    // 50 msgs at 1 send plus 4 hook msgs per balance
+    suite
+        .execute_as_manager(ExecuteMsg::AppendInitialBalances { balances })
+        .unwrap();
+    let status: DistributionStatusResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::DistributionStatus {})
+        .unwrap();
+    assert_eq!(status.remaining_count, 10);
+    assert_eq!(received(&suite, &hooks[0]).len(), 10);
+
+    // 30 msgs by default
+    suite
+        .execute_as_manager(ExecuteMsg::ContinueDistribution { limit: None })
+        .unwrap();
+    let status: DistributionStatusResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::DistributionStatus {})
+        .unwrap();
+    assert_eq!(status.remaining_count, 4);
+    assert_eq!(status.remaining_amount, Uint128::new(40));
+    for hook in &hooks {
+        assert_eq!(received(&suite, hook).len(), 16);
+    }
+}
@@ -0,0 +1,240 @@
+//! ibc-hooks executes memo calls from a sender derived from the channel and
+//! the original sender, which nobody holds keys for. Paid mints must credit
+//! the `recipient` named in the memo instead.
+
+mod common;
+
+use common::{Suite, BECH32_PREFIX};
+use cosmwasm_std::{coins, Addr, Api, CanonicalAddr, Decimal256, Uint128};
+use cw_contract::{
+    msg::ExecuteMsg,
+    state::models::{CurveType, RaisePricing},
+};
+use cw_multi_test::addons::MockApiBech32;
+use sha2::{Digest, Sha256};
+
+const PAYMENT_DENOM: &str = "uusdc";
+const HOOKS_CHANNEL: &str = "channel-0";
+const REMOTE_SENDER: &str = "cosmos1remotesender";
+
+/// Address ibc-hooks executes as for `original_sender` on `channel`:
+/// the ADR-028 address hash of `channel/original_sender` under the
+/// `ibc-wasm-hook-intermediary` type
+fn intermediary_sender(
+    channel: &str,
+    original_sender: &str,
+) -> Addr {
+    let type_hash = Sha256::digest(b"ibc-wasm-hook-intermediary");
+    let hash = Sha256::new()
+        .chain_update(type_hash)
+        .chain_update(format!("{}/{}", channel, original_sender))
+        .finalize();
+    MockApiBech32::new(BECH32_PREFIX)
+        .addr_humanize(&CanonicalAddr::from(hash.as_slice()))
+        .unwrap()
+}
+
+/// Fund the intermediary as the ICS-20 transfer would, returning it
+fn setup_hooks_sender(
+    suite: &mut Suite,
+    amount: u128,
+) -> Addr {
+    let sender = intermediary_sender(HOOKS_CHANNEL, REMOTE_SENDER);
+    suite.fund(&sender, coins(amount, PAYMENT_DENOM));
+    sender
+}
+
+fn assert_credited(
+    suite: &Suite,
+    sender: &Addr,
+    recipient: &Addr,
+    amount: Uint128,
+) {
+    assert_eq!(suite.balance(recipient, &suite.denom), amount);
+    assert_eq!(suite.balance(sender, &suite.denom), Uint128::zero());
+}
+
+#[test]
+fn intermediary_sender_is_a_valid_address() {
+    let suite = Suite::new();
+    let sender = intermediary_sender(HOOKS_CHANNEL, REMOTE_SENDER);
+    assert!(sender.as_str().starts_with(BECH32_PREFIX));
+    suite.app.api().addr_validate(sender.as_str()).unwrap();
+    assert_ne!(sender, intermediary_sender("channel-1", REMOTE_SENDER));
+}
+
+#[test]
+fn buy_through_hooks_mints_to_recipient() {
+    let mut suite = Suite::new();
+    let treasury = suite.addr("treasury");
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureSale {
+            price_denom: PAYMENT_DENOM.to_owned(),
+            price_per_token: Uint128::new(1_000_000),
+            start: None,
+            end: None,
+            max_per_address: None,
+            hard_cap: None,
+            treasury: Some(treasury),
+        })
+        .unwrap();
+
+    let sender = setup_hooks_sender(&mut suite, 500);
+    let recipient = suite.addr("recipient");
+    suite
+        .execute(
+            &sender,
+            ExecuteMsg::Buy {
+                recipient: Some(recipient.to_owned()),
+            },
+            &coins(500, PAYMENT_DENOM),
+        )
+        .unwrap();
+
+    assert_credited(&suite, &sender, &recipient, Uint128::new(500));
+}
+
+#[test]
+fn deposit_through_hooks_credits_recipient() {
+    let mut suite = Suite::new();
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureWrappedAsset {
+            underlying_denom: PAYMENT_DENOM.to_owned(),
+        })
+        .unwrap();
+
+    let sender = setup_hooks_sender(&mut suite, 500);
+    let recipient = suite.addr("recipient");
+    suite
+        .execute(
+            &sender,
+            ExecuteMsg::Deposit {
+                recipient: Some(recipient.to_owned()),
+            },
+            &coins(500, PAYMENT_DENOM),
+        )
+        .unwrap();
+    assert_credited(&suite, &sender, &recipient, Uint128::new(500));
+
+    // The deposit belongs to the recipient, who can unwrap it
+    let denom = suite.denom.to_owned();
+    suite
+        .execute(&recipient, ExecuteMsg::Withdraw {}, &coins(500, denom))
+        .unwrap();
+    assert_eq!(suite.balance(&recipient, PAYMENT_DENOM), Uint128::new(500));
+}
+
+#[test]
+fn curve_buy_through_hooks_mints_to_recipient() {
+    let mut suite = Suite::new();
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureCurve {
+            reserve_denom: PAYMENT_DENOM.to_owned(),
+            curve_type: CurveType::Linear {
+                slope: Decimal256::zero(),
+                base_price: Decimal256::one(),
+            },
+            entry_fee_bps: None,
+            exit_fee_bps: None,
+            treasury: None,
+        })
+        .unwrap();
+
+    let sender = setup_hooks_sender(&mut suite, 500);
+    let recipient = suite.addr("recipient");
+    suite
+        .execute(
+            &sender,
+            ExecuteMsg::BuyFromCurve {
+                recipient: Some(recipient.to_owned()),
+            },
+            &coins(500, PAYMENT_DENOM),
+        )
+        .unwrap();
+
+    assert!(!suite.balance(&recipient, &suite.denom).is_zero());
+    assert!(suite.balance(&sender, &suite.denom).is_zero());
+}
+
+#[test]
+fn bid_through_hooks_settles_to_recipient() {
+    let mut suite = Suite::new();
+    let treasury = suite.addr("treasury");
+    let now = suite.app.block_info().time;
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureAuction {
+            reserve_denom: PAYMENT_DENOM.to_owned(),
+            start_price: Uint128::new(2_000_000),
+            floor_price: Uint128::new(1_000_000),
+            start: now,
+            end: now.plus_seconds(100),
+            step_seconds: None,
+            supply: Uint128::new(1_000),
+            treasury: Some(treasury),
+        })
+        .unwrap();
+
+    let sender = setup_hooks_sender(&mut suite, 500);
+    let recipient = suite.addr("recipient");
+    suite
+        .execute(
+            &sender,
+            ExecuteMsg::PlaceBid {
+                recipient: Some(recipient.to_owned()),
+            },
+            &coins(500, PAYMENT_DENOM),
+        )
+        .unwrap();
+
+    suite.advance_seconds(100);
+    suite
+        .execute_as_manager(ExecuteMsg::SettleAuction { limit: None })
+        .unwrap();
+
+    // Unfilled at the end, the auction clears at the floor price
+    assert_credited(&suite, &sender, &recipient, Uint128::new(500));
+}
+
+#[test]
+fn contribution_through_hooks_is_claimed_by_recipient() {
+    let mut suite = Suite::new();
+    let treasury = suite.addr("treasury");
+    let now = suite.app.block_info().time;
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureRaise {
+            denom: PAYMENT_DENOM.to_owned(),
+            soft_cap: Uint128::new(100),
+            hard_cap: None,
+            start: now,
+            end: now.plus_seconds(100),
+            pricing: RaisePricing::FixedPrice {
+                price_per_token: Uint128::new(1_000_000),
+            },
+            treasury: Some(treasury),
+        })
+        .unwrap();
+
+    let sender = setup_hooks_sender(&mut suite, 500);
+    let recipient = suite.addr("recipient");
+    suite
+        .execute(
+            &sender,
+            ExecuteMsg::Contribute {
+                recipient: Some(recipient.to_owned()),
+            },
+            &coins(500, PAYMENT_DENOM),
+        )
+        .unwrap();
+
+    suite.advance_seconds(100);
+
+    // The intermediary has no contribution of its own to claim
+    suite
+        .execute(&sender, ExecuteMsg::Claim { round: None }, &[])
+        .unwrap_err();
+    suite
+        .execute(&recipient, ExecuteMsg::Claim { round: None }, &[])
+        .unwrap();
+
+    assert_credited(&suite, &sender, &recipient, Uint128::new(500));
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_std::{coins, Uint128};
+use cw_contract::{msg::ExecuteMsg, state::models::RaisePricing};
+
+const PAYMENT_DENOM: &str = "uusdc";
+
+fn configure_raise(
+    suite: &mut Suite,
+    soft_cap: u128,
+) {
+    let treasury = suite.addr("treasury");
+    let now = suite.app.block_info().time;
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureRaise {
+            denom: PAYMENT_DENOM.to_owned(),
+            soft_cap: Uint128::new(soft_cap),
+            hard_cap: None,
+            start: now,
+            end: now.plus_seconds(100),
+            pricing: RaisePricing::FixedPrice {
+                price_per_token: Uint128::new(1_000_000),
+            },
+            treasury: Some(treasury),
+        })
+        .unwrap();
+}
+
+#[test]
+fn raise_cannot_be_reconfigured_before_it_ends() {
+    let mut suite = Suite::new();
+    configure_raise(&mut suite, 100);
+
+    let alice = suite.addr("alice");
+    suite.fund(&alice, coins(500, PAYMENT_DENOM));
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::Contribute { recipient: None },
+            &coins(500, PAYMENT_DENOM),
+        )
+        .unwrap();
+
+    let err = suite
+        .execute_as_manager(ExecuteMsg::ConfigureRaise {
+            denom: PAYMENT_DENOM.to_owned(),
+            soft_cap: Uint128::new(100),
+            hard_cap: None,
+            start: suite.app.block_info().time,
+            end: suite.app.block_info().time.plus_seconds(100),
+            pricing: RaisePricing::FixedPrice {
+                price_per_token: Uint128::new(1_000_000),
+            },
+            treasury: None,
+        })
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("until it has ended"));
+}
+
+#[test]
+fn earlier_rounds_stay_claimable_after_reconfiguring() {
+    let mut suite = Suite::new();
+    configure_raise(&mut suite, 100);
+
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+    suite.fund(&alice, coins(500, PAYMENT_DENOM));
+    suite.fund(&bob, coins(1_000, PAYMENT_DENOM));
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::Contribute { recipient: None },
+            &coins(500, PAYMENT_DENOM),
+        )
+        .unwrap();
+    // A dust contribution that is never settled does not block the next round
+    suite
+        .execute(
+            &bob,
+            ExecuteMsg::Contribute { recipient: None },
+            &coins(1, PAYMENT_DENOM),
+        )
+        .unwrap();
+    suite.advance_seconds(100);
+
+    configure_raise(&mut suite, 10_000);
+    suite
+        .execute(
+            &bob,
+            ExecuteMsg::Contribute { recipient: None },
+            &coins(999, PAYMENT_DENOM),
+        )
+        .unwrap();
+    suite.advance_seconds(100);
+
+    // The first round succeeded, so alice claims tokens from it
+    suite
+        .execute(&alice, ExecuteMsg::Claim { round: Some(1) }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(500));
+    suite
+        .execute(&alice, ExecuteMsg::Claim { round: Some(1) }, &[])
+        .unwrap_err();
+
+    // The latest round missed its soft cap and refunds bob
+    suite
+        .execute(&bob, ExecuteMsg::Claim { round: None }, &[])
+        .unwrap_err();
+    suite
+        .execute(&bob, ExecuteMsg::Refund { round: None }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&bob, PAYMENT_DENOM), Uint128::new(999));
+
+    suite
+        .execute(&bob, ExecuteMsg::Claim { round: Some(1) }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&bob, &suite.denom), Uint128::new(1));
+}
@@ -0,0 +1,182 @@
+mod common;
+
+use common::{Suite, IBC_ESCROW};
+use cosmwasm_std::{coins, Addr, BankMsg, Uint128, Uint256};
+use cw_contract::msg::{
+    ExecuteMsg, IbcLifecycleComplete, InfoResponse, QueryMsg, RemoteTransfersResponse, SudoMsg,
+};
+use cw_multi_test::Executor;
+
+const CHANNEL: &str = "channel-0";
+
+fn mint_to_remote(
+    suite: &mut Suite,
+    amount: u128,
+    refund_address: Option<Addr>,
+) {
+    suite
+        .execute_as_manager(ExecuteMsg::MintToRemote {
+            channel_id: CHANNEL.to_owned(),
+            remote_recipient: "cosmos1recipient".to_owned(),
+            amount: Uint128::new(amount),
+            timeout: None,
+            memo: None,
+            refund_address,
+        })
+        .unwrap();
+}
+
+fn remote_transfers(suite: &Suite) -> RemoteTransfersResponse {
+    suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.contract,
+            &QueryMsg::RemoteTransfers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+}
+
+/// Return escrowed tokens to the contract, as the transfer module does
+/// before calling back on ack failure or timeout
+fn refund_escrow(
+    suite: &mut Suite,
+    amount: u128,
+) {
+    let escrow = suite.addr(IBC_ESCROW);
+    let denom = suite.denom.to_owned();
+    let contract = suite.contract.to_owned();
+    suite
+        .app
+        .execute(
+            escrow,
+            BankMsg::Send {
+                to_address: contract.to_string(),
+                amount: coins(amount, denom),
+            }
+            .into(),
+        )
+        .unwrap();
+}
+
+fn complete(
+    suite: &mut Suite,
+    msg: IbcLifecycleComplete,
+) {
+    let contract = suite.contract.to_owned();
+    suite
+        .app
+        .wasm_sudo(contract, &SudoMsg::IbcLifecycleComplete(msg))
+        .unwrap();
+}
+
+/// Net supply, as tracked by the contract's mint and burn stats
+fn supply(suite: &Suite) -> Uint256 {
+    let info: InfoResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::Info {})
+        .unwrap();
+    info.stats.amount_minted - info.stats.amount_burned
+}
+
+#[test]
+fn transfer_records_packet_sequence() {
+    let mut suite = Suite::new();
+    mint_to_remote(&mut suite, 100, None);
+    mint_to_remote(&mut suite, 200, None);
+
+    let escrow = suite.addr(IBC_ESCROW);
+    assert_eq!(suite.balance(&escrow, &suite.denom), Uint128::new(300));
+
+    let transfers = remote_transfers(&suite).transfers;
+    assert_eq!(transfers.len(), 2);
+    assert_eq!(transfers[0].sequence, 1);
+    assert_eq!(transfers[0].amount, Uint128::new(100));
+    assert_eq!(transfers[1].sequence, 2);
+    assert_eq!(transfers[1].channel_id, CHANNEL);
+}
+
+#[test]
+fn successful_ack_clears_transfer() {
+    let mut suite = Suite::new();
+    let refund = suite.addr("refund");
+    mint_to_remote(&mut suite, 100, Some(refund.to_owned()));
+
+    complete(
+        &mut suite,
+        IbcLifecycleComplete::IbcAck {
+            channel: CHANNEL.to_owned(),
+            sequence: 1,
+            ack: "{\"result\":\"AQ==\"}".to_owned(),
+            success: true,
+        },
+    );
+
+    assert!(remote_transfers(&suite).transfers.is_empty());
+    assert_eq!(suite.balance(&refund, &suite.denom), Uint128::zero());
+    assert_eq!(supply(&suite), Uint256::from(100u128));
+}
+
+#[test]
+fn failed_ack_refunds_refund_address() {
+    let mut suite = Suite::new();
+    let refund = suite.addr("refund");
+    mint_to_remote(&mut suite, 100, Some(refund.to_owned()));
+
+    refund_escrow(&mut suite, 100);
+    complete(
+        &mut suite,
+        IbcLifecycleComplete::IbcAck {
+            channel: CHANNEL.to_owned(),
+            sequence: 1,
+            ack: "{\"error\":\"failed\"}".to_owned(),
+            success: false,
+        },
+    );
+
+    assert!(remote_transfers(&suite).transfers.is_empty());
+    assert_eq!(suite.balance(&refund, &suite.denom), Uint128::new(100));
+    assert_eq!(
+        suite.balance(&suite.contract, &suite.denom),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn timeout_without_refund_address_burns() {
+    let mut suite = Suite::new();
+    mint_to_remote(&mut suite, 100, None);
+    mint_to_remote(&mut suite, 50, None);
+
+    refund_escrow(&mut suite, 100);
+    complete(
+        &mut suite,
+        IbcLifecycleComplete::IbcTimeout {
+            channel: CHANNEL.to_owned(),
+            sequence: 1,
+        },
+    );
+
+    assert_eq!(
+        suite.balance(&suite.contract, &suite.denom),
+        Uint128::zero()
+    );
+    assert_eq!(supply(&suite), Uint256::from(50u128));
+
+    // Only the timed-out transfer is settled, and only once
+    let transfers = remote_transfers(&suite).transfers;
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].sequence, 2);
+    complete(
+        &mut suite,
+        IbcLifecycleComplete::IbcTimeout {
+            channel: CHANNEL.to_owned(),
+            sequence: 1,
+        },
+    );
+    assert_eq!(supply(&suite), Uint256::from(50u128));
+}
@@ -0,0 +1,276 @@
+mod common;
+
+use common::{Suite, BOND_DENOM, UNBONDING_TIME};
+use cosmwasm_std::{coins, Decimal256, Uint128};
+use cw_contract::{
+    msg::{ExecuteMsg, QueryMsg, StakingStateResponse},
+    state::models::StakingValidator,
+};
+
+const YEAR: u64 = 365 * 24 * 60 * 60;
+
+fn setup() -> Suite {
+    let mut suite = Suite::new();
+    let validators = (0..2)
+        .map(|i| StakingValidator {
+            address: suite.validator(i),
+            weight: 1,
+        })
+        .collect();
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureStaking {
+            bond_denom: None,
+            validators,
+            unbonding_period: UNBONDING_TIME,
+        })
+        .unwrap();
+    suite
+}
+
+fn staking_state(suite: &Suite) -> StakingStateResponse {
+    suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::StakingState {})
+        .unwrap()
+}
+
+fn total_delegated(suite: &Suite) -> Uint128 {
+    suite
+        .app
+        .wrap()
+        .query_all_delegations(&suite.contract)
+        .unwrap()
+        .iter()
+        .map(|d| d.amount.amount)
+        .sum()
+}
+
+fn stake(
+    suite: &mut Suite,
+    name: &str,
+    amount: u128,
+) {
+    let staker = suite.addr(name);
+    suite.fund(&staker, coins(amount, BOND_DENOM));
+    suite
+        .execute(
+            &staker,
+            ExecuteMsg::Stake { recipient: None },
+            &coins(amount, BOND_DENOM),
+        )
+        .unwrap();
+}
+
+#[test]
+fn stake_delegates_and_mints_receipts() {
+    let mut suite = setup();
+    stake(&mut suite, "staker", 1_000);
+
+    let staker = suite.addr("staker");
+    let denom = suite.denom.to_owned();
+    assert_eq!(suite.balance(&staker, &denom), Uint128::new(1_000));
+    assert_eq!(total_delegated(&suite), Uint128::new(1_000));
+
+    let state = staking_state(&suite).state;
+    assert_eq!(state.total_bonded, Uint128::new(1_000));
+    assert_eq!(state.total_supply, Uint128::new(1_000));
+}
+
+#[test]
+fn compound_raises_exchange_rate() {
+    let mut suite = setup();
+    stake(&mut suite, "staker", 1_000);
+
+    suite.advance_seconds(YEAR);
+    let keeper = suite.addr("keeper");
+    suite
+        .execute(&keeper, ExecuteMsg::Compound {}, &[])
+        .unwrap();
+
+    // 10% APR with no commission
+    let resp = staking_state(&suite);
+    assert_eq!(resp.state.total_bonded, Uint128::new(1_100));
+    assert_eq!(resp.exchange_rate, Decimal256::percent(110));
+    assert_eq!(total_delegated(&suite), Uint128::new(1_100));
+}
+
+#[test]
+fn compound_ignores_untracked_bond_denom() {
+    let mut suite = setup();
+    stake(&mut suite, "staker", 1_000);
+
+    // Funds sent to the contract by anyone else are not rewards
+    let contract = suite.contract.to_owned();
+    suite.fund(&contract, coins(5_000, BOND_DENOM));
+
+    let keeper = suite.addr("keeper");
+    let err = suite
+        .execute(&keeper, ExecuteMsg::Compound {}, &[])
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("nothing to compound"));
+
+    suite.advance_seconds(YEAR);
+    suite
+        .execute(&keeper, ExecuteMsg::Compound {}, &[])
+        .unwrap();
+    assert_eq!(total_delegated(&suite), Uint128::new(1_100));
+    assert_eq!(
+        suite.balance(&suite.contract, BOND_DENOM),
+        Uint128::new(5_000)
+    );
+}
+
+#[test]
+fn rewards_withdrawn_by_stake_are_compounded() {
+    let mut suite = setup();
+    stake(&mut suite, "staker", 1_000);
+
+    suite.advance_seconds(YEAR);
+    stake(&mut suite, "late_staker", 1_100);
+
+    let state = staking_state(&suite).state;
+    assert_eq!(state.idle_rewards, Uint128::new(100));
+
+    let keeper = suite.addr("keeper");
+    suite
+        .execute(&keeper, ExecuteMsg::Compound {}, &[])
+        .unwrap();
+
+    let state = staking_state(&suite).state;
+    assert_eq!(state.idle_rewards, Uint128::zero());
+    assert_eq!(state.total_bonded, Uint128::new(2_200));
+    assert_eq!(total_delegated(&suite), Uint128::new(2_200));
+}
+
+#[test]
+fn unstake_and_claim_after_unbonding() {
+    let mut suite = setup();
+    stake(&mut suite, "staker", 1_000);
+
+    suite.advance_seconds(YEAR);
+    let keeper = suite.addr("keeper");
+    suite
+        .execute(&keeper, ExecuteMsg::Compound {}, &[])
+        .unwrap();
+
+    let staker = suite.addr("staker");
+    let denom = suite.denom.to_owned();
+    suite
+        .execute(&staker, ExecuteMsg::Unstake {}, &coins(500, &denom))
+        .unwrap();
+
+    assert_eq!(suite.balance(&staker, &denom), Uint128::new(500));
+    let state = staking_state(&suite).state;
+    assert_eq!(state.total_supply, Uint128::new(500));
+    assert_eq!(state.total_bonded, Uint128::new(550));
+    assert_eq!(state.total_unbonding, Uint128::new(550));
+
+    let err = suite
+        .execute(&staker, ExecuteMsg::ClaimUnbonded {}, &[])
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("no matured unbonding claims"));
+
+    suite.advance_seconds(UNBONDING_TIME);
+    suite
+        .execute(&staker, ExecuteMsg::ClaimUnbonded {}, &[])
+        .unwrap();
+
+    assert_eq!(suite.balance(&staker, BOND_DENOM), Uint128::new(550));
+    assert_eq!(staking_state(&suite).state.total_unbonding, Uint128::zero());
+}
+
+#[test]
+fn pending_rewards_back_receipts() {
+    let mut suite = setup();
+    stake(&mut suite, "staker", 1_000);
+
+    suite.advance_seconds(YEAR);
+    let resp = staking_state(&suite);
+    assert_eq!(resp.pending_rewards, Uint128::new(100));
+    assert_eq!(resp.exchange_rate, Decimal256::percent(110));
+
+    // The late staker buys in at the rate including accrued rewards
+    stake(&mut suite, "late_staker", 1_100);
+    let late_staker = suite.addr("late_staker");
+    let denom = suite.denom.to_owned();
+    assert_eq!(suite.balance(&late_staker, &denom), Uint128::new(1_000));
+
+    // The share of idle rewards is paid without undelegating it
+    let staker = suite.addr("staker");
+    suite
+        .execute(&staker, ExecuteMsg::Unstake {}, &coins(1_000, &denom))
+        .unwrap();
+    let state = staking_state(&suite).state;
+    assert_eq!(state.total_unbonding, Uint128::new(1_100));
+    assert_eq!(state.idle_rewards, Uint128::new(50));
+    assert_eq!(state.total_bonded, Uint128::new(1_050));
+    assert_eq!(total_delegated(&suite), Uint128::new(1_050));
+
+    suite.advance_seconds(UNBONDING_TIME);
+    suite
+        .execute(&staker, ExecuteMsg::ClaimUnbonded {}, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&staker, BOND_DENOM), Uint128::new(1_100));
+}
+
+#[test]
+fn claims_wait_for_the_staking_module() {
+    let mut suite = Suite::new();
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureStaking {
+            bond_denom: None,
+            validators: vec![StakingValidator {
+                address: suite.validator(0),
+                weight: 1,
+            }],
+            unbonding_period: 1,
+        })
+        .unwrap();
+
+    // The wrapped-asset mode holds the same denom
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureWrappedAsset {
+            underlying_denom: BOND_DENOM.to_owned(),
+        })
+        .unwrap();
+    let depositor = suite.addr("depositor");
+    suite.fund(&depositor, coins(5_000, BOND_DENOM));
+    suite
+        .execute(
+            &depositor,
+            ExecuteMsg::Deposit { recipient: None },
+            &coins(5_000, BOND_DENOM),
+        )
+        .unwrap();
+
+    stake(&mut suite, "staker", 1_000);
+    let staker = suite.addr("staker");
+    let denom = suite.denom.to_owned();
+    suite
+        .execute(&staker, ExecuteMsg::Unstake {}, &coins(1_000, &denom))
+        .unwrap();
+
+    // The claim has matured, but the chain has not returned the tokens
+    suite.advance_seconds(1);
+    let err = suite
+        .execute(&staker, ExecuteMsg::ClaimUnbonded {}, &[])
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("returned by the staking module"));
+
+    suite.advance_seconds(UNBONDING_TIME);
+    suite
+        .execute(&staker, ExecuteMsg::ClaimUnbonded {}, &[])
+        .unwrap();
+    assert_eq!(suite.balance(&staker, BOND_DENOM), Uint128::new(1_000));
+    assert_eq!(
+        suite.balance(&suite.contract, BOND_DENOM),
+        Uint128::new(5_000)
+    );
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_std::{testing::mock_env, Binary, Uint128};
+use cw_contract::{
+    msg::ExecuteMsg,
+    state::models::{VoucherKeyType, VoucherSigner},
+};
+use cw_multi_test::{error::AnyResult, AppResponse};
+
+// Fixed keys and signatures over payloads for this suite's deterministic
+// chain ID, contract and recipient addresses, expiring an hour after genesis.
+// Secp256k1 signs sha256(payload) with a low-S signature; ed25519 signs the
+// payload bytes.
+const SECP256K1_PUBLIC_KEY: &str =
+    "0363f56223f1d49167a1fe3e46765ac16ff1b8e2cb2476c0bf360a4a1b5ee4d539";
+const ED25519_PUBLIC_KEY: &str = "5deadb44af0f88e711a5aab73991b084b193a047b13ea05f99757135aba14423";
+
+/// Secp256k1 voucher for 1000 with nonce 1
+const SECP256K1_SIGNATURE: &str = "8394097ce4477497cb5c08d361f776b8e5f7be3a4ffef0f6973b5874a81e87a97c8d647de79453a6cd8f06fa79445b5544190cbfcdc8ec3939c6b3cf166c50ca";
+/// Ed25519 voucher for 500 with nonce 2
+const ED25519_SIGNATURE: &str = "1ed94efc6ecea78ce065c90fd51a02d15d9c01ceb5ed5f4126730e69c189e4da7434c6fd3d660e77adb33adf6fb8419ec88e8844089051d573977eff73331c05";
+/// Secp256k1 voucher for 1000 with nonce 3, signed for chain `other-chain-1`
+const OTHER_CHAIN_SIGNATURE: &str = "3522c53b4d801fea40ca01a386dee7e99734d7ed978a2c97fe306e4af460a71d7808d2edc273d6ec9c95e6475a3f896e1f96c5904a0635b862c8173b3469148c";
+/// Secp256k1 voucher for 1000 with nonce 4, signed for another contract
+const OTHER_CONTRACT_SIGNATURE: &str = "38f2e47bdf70c385e067876d926770ab7963da206efdc80fea12394c3d1fe5c3445a5ac6d7ccd8760ceea02f4cbe57b610e9016cf34f0d8e1033980944c3a047";
+
+const EXPIRES_AFTER: u64 = 3600;
+
+fn setup(key_type: VoucherKeyType) -> Suite {
+    let mut suite = Suite::new();
+    let public_key = match key_type {
+        VoucherKeyType::Secp256k1 => SECP256K1_PUBLIC_KEY,
+        VoucherKeyType::Ed25519 => ED25519_PUBLIC_KEY,
+    };
+    suite
+        .execute_as_manager(ExecuteMsg::SetVoucherSigner {
+            signer: Some(VoucherSigner {
+                key_type,
+                public_key: Binary::from(hex::decode(public_key).unwrap()),
+            }),
+        })
+        .unwrap();
+    suite
+}
+
+fn redeem(
+    suite: &mut Suite,
+    amount: u128,
+    nonce: u64,
+    signature: &str,
+) -> AnyResult<AppResponse> {
+    let recipient = suite.addr("alice");
+    let relayer = suite.addr("relayer");
+    suite.execute(
+        &relayer,
+        ExecuteMsg::RedeemVoucher {
+            recipient,
+            amount: Uint128::new(amount),
+            nonce,
+            expires: mock_env().block.time.plus_seconds(EXPIRES_AFTER),
+            signature: Binary::from(hex::decode(signature).unwrap()),
+        },
+        &[],
+    )
+}
+
+#[test]
+fn redeem_secp256k1_voucher() {
+    let mut suite = setup(VoucherKeyType::Secp256k1);
+    redeem(&mut suite, 1_000, 1, SECP256K1_SIGNATURE).unwrap();
+
+    let alice = suite.addr("alice");
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(1_000));
+}
+
+#[test]
+fn redeem_ed25519_voucher() {
+    let mut suite = setup(VoucherKeyType::Ed25519);
+    redeem(&mut suite, 500, 2, ED25519_SIGNATURE).unwrap();
+
+    let alice = suite.addr("alice");
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(500));
+}
+
+#[test]
+fn tampered_voucher_is_rejected() {
+    let mut suite = setup(VoucherKeyType::Secp256k1);
+    let err = redeem(&mut suite, 1_001, 1, SECP256K1_SIGNATURE).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("invalid voucher signature"));
+
+    // A signature from the other scheme doesn't verify either
+    redeem(&mut suite, 500, 2, ED25519_SIGNATURE).unwrap_err();
+}
+
+#[test]
+fn voucher_nonce_cannot_be_replayed() {
+    let mut suite = setup(VoucherKeyType::Secp256k1);
+    redeem(&mut suite, 1_000, 1, SECP256K1_SIGNATURE).unwrap();
+
+    let err = redeem(&mut suite, 1_000, 1, SECP256K1_SIGNATURE).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("voucher nonce 1 already used"));
+    let alice = suite.addr("alice");
+    assert_eq!(suite.balance(&alice, &suite.denom), Uint128::new(1_000));
+}
+
+#[test]
+fn expired_voucher_is_rejected() {
+    let mut suite = setup(VoucherKeyType::Secp256k1);
+    suite.advance_seconds(EXPIRES_AFTER);
+
+    let err = redeem(&mut suite, 1_000, 1, SECP256K1_SIGNATURE).unwrap_err();
+    assert!(err.root_cause().to_string().contains("voucher expired"));
+}
+
+#[test]
+fn voucher_is_bound_to_chain_and_contract() {
+    let mut suite = setup(VoucherKeyType::Secp256k1);
+
+    let err = redeem(&mut suite, 1_000, 3, OTHER_CHAIN_SIGNATURE).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("invalid voucher signature"));
+    let err = redeem(&mut suite, 1_000, 4, OTHER_CONTRACT_SIGNATURE).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("invalid voucher signature"));
+}
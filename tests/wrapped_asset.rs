@@ -0,0 +1,113 @@
+mod common;
+
+use common::Suite;
+use cosmwasm_std::{coins, BankMsg, Uint128};
+use cw_contract::msg::{BackingResponse, ExecuteMsg, QueryMsg};
+use cw_multi_test::Executor;
+
+const UNDERLYING_DENOM: &str = "uatom";
+
+fn setup() -> Suite {
+    let mut suite = Suite::new();
+    suite
+        .execute_as_manager(ExecuteMsg::ConfigureWrappedAsset {
+            underlying_denom: UNDERLYING_DENOM.to_owned(),
+        })
+        .unwrap();
+    suite
+}
+
+fn backing(suite: &Suite) -> BackingResponse {
+    suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.contract, &QueryMsg::Backing {})
+        .unwrap()
+}
+
+#[test]
+fn backing_reports_the_underlying_balance() {
+    let mut suite = setup();
+    let alice = suite.addr("alice");
+    suite.fund(&alice, coins(500, UNDERLYING_DENOM));
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::Deposit { recipient: None },
+            &coins(500, UNDERLYING_DENOM),
+        )
+        .unwrap();
+
+    let backing = backing(&suite);
+    assert_eq!(backing.outstanding, Uint128::new(500));
+    assert_eq!(backing.underlying_balance, Uint128::new(500));
+    assert!(backing.is_backed);
+}
+
+#[test]
+fn transferred_receipts_can_be_withdrawn() {
+    let mut suite = setup();
+    let alice = suite.addr("alice");
+    let bob = suite.addr("bob");
+    suite.fund(&alice, coins(500, UNDERLYING_DENOM));
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::Deposit { recipient: None },
+            &coins(500, UNDERLYING_DENOM),
+        )
+        .unwrap();
+
+    // Receipts are fungible, so whoever holds them can redeem
+    let denom = suite.denom.to_owned();
+    suite
+        .app
+        .execute(
+            alice.to_owned(),
+            BankMsg::Send {
+                to_address: bob.to_string(),
+                amount: coins(200, &denom),
+            }
+            .into(),
+        )
+        .unwrap();
+    suite
+        .execute(&bob, ExecuteMsg::Withdraw {}, &coins(200, &denom))
+        .unwrap();
+    assert_eq!(suite.balance(&bob, UNDERLYING_DENOM), Uint128::new(200));
+    assert_eq!(backing(&suite).outstanding, Uint128::new(300));
+}
+
+#[test]
+fn withdrawals_are_capped_by_outstanding_receipts() {
+    let mut suite = setup();
+    let alice = suite.addr("alice");
+    suite.fund(&alice, coins(500, UNDERLYING_DENOM));
+    suite
+        .execute(
+            &alice,
+            ExecuteMsg::Deposit { recipient: None },
+            &coins(500, UNDERLYING_DENOM),
+        )
+        .unwrap();
+
+    // Tokens minted outside the wrapped mode are not owed any underlying
+    suite
+        .execute_as_manager(ExecuteMsg::Mint {
+            recipient: alice.to_owned(),
+            amount: Uint128::new(100),
+        })
+        .unwrap();
+    let denom = suite.denom.to_owned();
+    suite
+        .execute(&alice, ExecuteMsg::Withdraw {}, &coins(600, &denom))
+        .unwrap_err();
+    suite
+        .execute(&alice, ExecuteMsg::Withdraw {}, &coins(500, &denom))
+        .unwrap();
+
+    let backing = backing(&suite);
+    assert_eq!(backing.outstanding, Uint128::zero());
+    assert_eq!(backing.underlying_balance, Uint128::zero());
+    assert!(backing.is_backed);
+}